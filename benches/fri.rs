@@ -17,6 +17,7 @@ fn bench_fri_commit(c: &mut Criterion) {
     const NUM_QUERIES: usize = 40;
     const FAN_IN: usize = 4;
     const BASE_DIMENSION: usize = 16;
+    const POW_BITS: u32 = 0;
 
     for &domain_size in &[64, 128, 256, 512] {
         let evals = create_evaluations(domain_size);
@@ -33,6 +34,7 @@ fn bench_fri_commit(c: &mut Criterion) {
                     NUM_QUERIES,
                     FAN_IN,
                     BASE_DIMENSION,
+                    POW_BITS,
                 );
 
                 let evals_clone = evals.clone();
@@ -0,0 +1,353 @@
+//! Calldata encoding module
+//!
+//! Flattens a native [`FriProof`] (and the [`Commitment`] it was opened
+//! against) into the field-element/hash array layout a Cairo/Starknet
+//! verifier contract expects: every scalar as a fixed-width big-endian
+//! `FeltWord`, every Merkle root or node hash as a 32-byte word, and the
+//! per-query openings grouped by round so the on-chain verifier can replay
+//! each fold and authentication check exactly as [`crate::fri::FriVerifier`]
+//! does. Every variable-length piece (the number of folding rounds, the
+//! number of queries, each round's auth path depth) is written as an
+//! explicit length word read back from [`Commitment::metadata`] and the
+//! proof itself rather than hard-coded, so the layout adapts to whatever
+//! parameters the commitment was produced under. A JSON export is also
+//! provided for tooling that doesn't want to deal with the raw word layout.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Commitment, FriProof, FriedaError, Result, M31};
+
+/// A single 32-byte calldata word, always encoded big-endian
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct FeltWord(pub [u8; 32]);
+
+impl FeltWord {
+    fn from_u64(value: u64) -> Self {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        FeltWord(word)
+    }
+
+    fn to_u64(self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.0[24..]);
+        u64::from_be_bytes(bytes)
+    }
+
+    fn from_m31(value: M31) -> Self {
+        Self::from_u64(value.0 as u64)
+    }
+
+    fn from_root(root: [u8; 32]) -> Self {
+        FeltWord(root)
+    }
+
+    fn to_root(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+/// A single authenticated sibling opening, mirroring [`crate::fri::LayerOpening`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CalldataOpening {
+    pub index: u64,
+    pub value: FeltWord,
+    pub auth_path: Vec<FeltWord>,
+}
+
+/// One query's full folding chain, mirroring [`crate::fri::QueryInfo`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CalldataQuery {
+    pub index: u64,
+    pub round_openings: Vec<Vec<CalldataOpening>>,
+}
+
+/// A structured, JSON-friendly view of a [`FriProof`]'s calldata fields
+///
+/// This mirrors [`FriProof::to_calldata`]'s flat word layout field-for-field,
+/// so the two encodings can be round-tripped through [`FriProof::from_calldata`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CalldataFriProof {
+    pub domain_size: u64,
+    pub expansion_factor: u64,
+    pub batch_size: u64,
+    pub field_size: u64,
+    pub bit_length: u64,
+    pub commitment_root: [u8; 32],
+    pub pow_nonce: u64,
+    pub betas: Vec<FeltWord>,
+    pub layer_roots: Vec<[u8; 32]>,
+    pub final_layer: Vec<FeltWord>,
+    pub queries: Vec<CalldataQuery>,
+}
+
+/// A cursor over a `&[FeltWord]` slice, rejecting reads past the end
+struct WordReader<'a> {
+    words: &'a [FeltWord],
+    pos: usize,
+}
+
+impl<'a> WordReader<'a> {
+    fn new(words: &'a [FeltWord]) -> Self {
+        Self { words, pos: 0 }
+    }
+
+    fn next_word(&mut self) -> Result<FeltWord> {
+        let word = *self.words.get(self.pos).ok_or_else(|| {
+            FriedaError::InvalidInput("calldata truncated: expected another word".to_string())
+        })?;
+        self.pos += 1;
+        Ok(word)
+    }
+
+    fn next_u64(&mut self) -> Result<u64> {
+        Ok(self.next_word()?.to_u64())
+    }
+
+    fn next_root(&mut self) -> Result<[u8; 32]> {
+        Ok(self.next_word()?.to_root())
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.words.len()
+    }
+}
+
+impl FriProof {
+    /// Flattens this proof, paired with `commitment`, into the felt/hash
+    /// word array a Starknet verifier contract expects
+    ///
+    /// Layout: the commitment's metadata (`domain_size`, `expansion_factor`,
+    /// `batch_size`, `field_size`, `bit_length`), the commitment root, the
+    /// proof-of-work nonce, the number of folding rounds followed by that
+    /// many betas and that many layer roots, the final layer (length-
+    /// prefixed), and then, for each query, its index followed by every
+    /// round's `fan_in` sibling openings (each length-prefixed, so on-chain
+    /// replay doesn't need to know `fan_in` ahead of time) -- exactly the
+    /// data [`crate::fri::FriVerifier::verify`] needs to replay every fold
+    /// and Merkle check.
+    pub fn to_calldata(&self, commitment: &Commitment) -> Vec<FeltWord> {
+        let metadata = &commitment.metadata;
+        let mut words = vec![
+            FeltWord::from_u64(metadata.domain_size as u64),
+            FeltWord::from_u64(metadata.expansion_factor as u64),
+            FeltWord::from_u64(metadata.batch_size as u64),
+            FeltWord::from_u64(metadata.field_size as u64),
+            FeltWord::from_u64(metadata.bit_length as u64),
+            FeltWord::from_root(commitment.root),
+            FeltWord::from_u64(self.pow_nonce),
+            FeltWord::from_u64(self.betas.len() as u64),
+        ];
+        words.extend(self.betas.iter().map(|&beta| FeltWord::from_m31(beta)));
+        words.extend(self.layers.iter().map(|layer| FeltWord::from_root(layer.root)));
+
+        words.push(FeltWord::from_u64(self.final_layer.len() as u64));
+        words.extend(self.final_layer.iter().map(|&value| FeltWord::from_m31(value)));
+
+        words.push(FeltWord::from_u64(self.query_info.len() as u64));
+        for query in &self.query_info {
+            words.push(FeltWord::from_u64(query.index as u64));
+            for round_openings in &query.round_openings {
+                words.push(FeltWord::from_u64(round_openings.len() as u64));
+                for opening in round_openings {
+                    words.push(FeltWord::from_u64(opening.index as u64));
+                    words.push(FeltWord::from_m31(opening.value));
+                    words.push(FeltWord::from_u64(opening.auth_path.len() as u64));
+                    words.extend(opening.auth_path.iter().map(|&node| FeltWord::from_root(node)));
+                }
+            }
+        }
+
+        words
+    }
+
+    /// Exports this proof's calldata fields as JSON, for tooling that works
+    /// with a structured representation rather than a raw word array
+    pub fn calldata_json(&self, commitment: &Commitment) -> Result<String> {
+        let calldata = Self::calldata_struct(self, commitment);
+        serde_json::to_string(&calldata).map_err(|e| FriedaError::InvalidInput(e.to_string()))
+    }
+
+    fn calldata_struct(&self, commitment: &Commitment) -> CalldataFriProof {
+        let metadata = &commitment.metadata;
+        CalldataFriProof {
+            domain_size: metadata.domain_size as u64,
+            expansion_factor: metadata.expansion_factor as u64,
+            batch_size: metadata.batch_size as u64,
+            field_size: metadata.field_size as u64,
+            bit_length: metadata.bit_length as u64,
+            commitment_root: commitment.root,
+            pow_nonce: self.pow_nonce,
+            betas: self.betas.iter().map(|&beta| FeltWord::from_m31(beta)).collect(),
+            layer_roots: self.layers.iter().map(|layer| layer.root).collect(),
+            final_layer: self.final_layer.iter().map(|&value| FeltWord::from_m31(value)).collect(),
+            queries: self
+                .query_info
+                .iter()
+                .map(|query| CalldataQuery {
+                    index: query.index as u64,
+                    round_openings: query
+                        .round_openings
+                        .iter()
+                        .map(|round_openings| {
+                            round_openings
+                                .iter()
+                                .map(|opening| CalldataOpening {
+                                    index: opening.index as u64,
+                                    value: FeltWord::from_m31(opening.value),
+                                    auth_path: opening
+                                        .auth_path
+                                        .iter()
+                                        .map(|&node| FeltWord::from_root(node))
+                                        .collect(),
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Parses the flat word array produced by [`Self::to_calldata`] back
+    /// into a structured [`CalldataFriProof`], rejecting truncated or
+    /// over-long input
+    pub fn from_calldata(words: &[FeltWord]) -> Result<CalldataFriProof> {
+        let mut reader = WordReader::new(words);
+
+        let domain_size = reader.next_u64()?;
+        let expansion_factor = reader.next_u64()?;
+        let batch_size = reader.next_u64()?;
+        let field_size = reader.next_u64()?;
+        let bit_length = reader.next_u64()?;
+        let commitment_root = reader.next_root()?;
+        let pow_nonce = reader.next_u64()?;
+
+        let num_rounds = reader.next_u64()? as usize;
+        let betas = (0..num_rounds)
+            .map(|_| reader.next_word())
+            .collect::<Result<Vec<_>>>()?;
+        let layer_roots = (0..num_rounds)
+            .map(|_| reader.next_root())
+            .collect::<Result<Vec<_>>>()?;
+
+        let final_layer_len = reader.next_u64()? as usize;
+        let final_layer = (0..final_layer_len)
+            .map(|_| reader.next_word())
+            .collect::<Result<Vec<_>>>()?;
+
+        let num_queries = reader.next_u64()? as usize;
+        let queries = (0..num_queries)
+            .map(|_| {
+                let index = reader.next_u64()?;
+                let round_openings = (0..num_rounds)
+                    .map(|_| {
+                        let num_openings = reader.next_u64()? as usize;
+                        (0..num_openings)
+                            .map(|_| {
+                                let index = reader.next_u64()?;
+                                let value = reader.next_word()?;
+                                let auth_path_len = reader.next_u64()? as usize;
+                                let auth_path = (0..auth_path_len)
+                                    .map(|_| reader.next_word())
+                                    .collect::<Result<Vec<_>>>()?;
+                                Ok(CalldataOpening {
+                                    index,
+                                    value,
+                                    auth_path,
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(CalldataQuery {
+                    index,
+                    round_openings,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if !reader.at_end() {
+            return Err(FriedaError::InvalidInput(
+                "calldata has trailing words past the fields the format declares".to_string(),
+            ));
+        }
+
+        Ok(CalldataFriProof {
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            bit_length,
+            commitment_root,
+            pow_nonce,
+            betas,
+            layer_roots,
+            final_layer,
+            queries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::da;
+
+    #[test]
+    fn test_calldata_round_trips() {
+        let data = include_bytes!("../blob");
+        let (commitment, context) = da::commit_with_context(data).unwrap();
+        let proof = da::generate_proof(&context).unwrap();
+
+        let words = proof.to_calldata(&commitment);
+        let decoded = FriProof::from_calldata(&words).unwrap();
+
+        assert_eq!(decoded.domain_size, commitment.metadata.domain_size as u64);
+        assert_eq!(decoded.commitment_root, commitment.root);
+        assert_eq!(decoded.pow_nonce, proof.pow_nonce);
+        assert_eq!(decoded.betas.len(), proof.betas.len());
+        assert_eq!(decoded.layer_roots.len(), proof.layers.len());
+        assert_eq!(decoded.final_layer.len(), proof.final_layer.len());
+        assert_eq!(decoded.queries.len(), proof.query_info.len());
+        assert_eq!(
+            decoded.queries[0].round_openings.len(),
+            proof.query_info[0].round_openings.len()
+        );
+    }
+
+    #[test]
+    fn test_from_calldata_rejects_truncated_input() {
+        let data = include_bytes!("../blob");
+        let (commitment, context) = da::commit_with_context(data).unwrap();
+        let proof = da::generate_proof(&context).unwrap();
+
+        let mut words = proof.to_calldata(&commitment);
+        words.pop();
+
+        assert!(FriProof::from_calldata(&words).is_err());
+    }
+
+    #[test]
+    fn test_from_calldata_rejects_over_long_input() {
+        let data = include_bytes!("../blob");
+        let (commitment, context) = da::commit_with_context(data).unwrap();
+        let proof = da::generate_proof(&context).unwrap();
+
+        let mut words = proof.to_calldata(&commitment);
+        words.push(FeltWord::from_u64(0));
+
+        assert!(FriProof::from_calldata(&words).is_err());
+    }
+
+    #[test]
+    fn test_calldata_json_round_trips_through_serde() {
+        let data = include_bytes!("../blob");
+        let (commitment, context) = da::commit_with_context(data).unwrap();
+        let proof = da::generate_proof(&context).unwrap();
+
+        let json = proof.calldata_json(&commitment).unwrap();
+        let decoded: CalldataFriProof = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, proof.calldata_struct(&commitment));
+    }
+}
@@ -0,0 +1,269 @@
+//! Binary wire codec for [`Commitment`] and [`Proof`]
+//!
+//! A compact, versioned byte format so a DA node can publish a commitment
+//! on-chain and ship a proof to a light client without depending on a
+//! particular serialization library: a one-byte format version, fixed-width
+//! little-endian integers, and length-prefixed vectors for the query
+//! evaluations.
+
+use stwo_prover::core::fields::{cm31::CM31, m31::M31, qm31::QM31};
+
+use crate::{commit::Commitment, proof::Proof};
+
+const WIRE_FORMAT_VERSION: u8 = 1;
+
+/// Errors returned when decoding a buffer produced by [`commitment_to_bytes`]
+/// or [`Proof::to_bytes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecError {
+    /// The buffer ended before a fixed-width field or a declared vector
+    /// could be read in full
+    Truncated,
+    /// The buffer has trailing bytes past the fields the format declares
+    TooLong,
+    /// The leading format-version byte doesn't match a version this build
+    /// understands
+    UnsupportedVersion(u8),
+}
+
+/// A structured, wire-decoded view of a [`Proof`]
+///
+/// This mirrors [`Proof::to_bytes`]'s field layout field-for-field, along
+/// with the seed it was paired with; it doesn't carry the full FRI
+/// decommitment data (Merkle authentication paths, inner-layer foldings),
+/// which stays opaque to this codec, so it isn't itself a [`Proof`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WireProof {
+    pub commitment_root: Commitment,
+    pub coset_log_size: u32,
+    pub log_size_bound: u32,
+    pub log_blowup_factor: u32,
+    pub log_last_layer_degree_bound: u32,
+    pub n_queries: u32,
+    pub pow_bits: u32,
+    pub proof_of_work: u64,
+    pub seed: Option<u64>,
+    pub evaluations: Vec<QM31>,
+}
+
+fn read_bytes<'a>(bytes: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8], CodecError> {
+    let end = *pos + len;
+    let slice = bytes.get(*pos..end).ok_or(CodecError::Truncated)?;
+    *pos = end;
+    Ok(slice)
+}
+
+fn read_u8(bytes: &[u8], pos: &mut usize) -> Result<u8, CodecError> {
+    Ok(read_bytes(bytes, pos, 1)?[0])
+}
+
+fn read_u32(bytes: &[u8], pos: &mut usize) -> Result<u32, CodecError> {
+    Ok(u32::from_le_bytes(read_bytes(bytes, pos, 4)?.try_into().unwrap()))
+}
+
+fn read_u64(bytes: &[u8], pos: &mut usize) -> Result<u64, CodecError> {
+    Ok(u64::from_le_bytes(read_bytes(bytes, pos, 8)?.try_into().unwrap()))
+}
+
+/// Encodes a [`Commitment`] as a one-byte format version followed by the
+/// 32-byte Merkle root
+pub fn commitment_to_bytes(commitment: &Commitment) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(33);
+    bytes.push(WIRE_FORMAT_VERSION);
+    bytes.extend_from_slice(commitment);
+    bytes
+}
+
+/// Decodes a buffer produced by [`commitment_to_bytes`], rejecting
+/// truncated, over-long, or version-mismatched input
+pub fn commitment_from_bytes(bytes: &[u8]) -> Result<Commitment, CodecError> {
+    let mut pos = 0;
+    let version = read_u8(bytes, &mut pos)?;
+    if version != WIRE_FORMAT_VERSION {
+        return Err(CodecError::UnsupportedVersion(version));
+    }
+    let root = read_bytes(bytes, &mut pos, 32)?;
+    if pos != bytes.len() {
+        return Err(CodecError::TooLong);
+    }
+    let mut commitment = [0u8; 32];
+    commitment.copy_from_slice(root);
+    Ok(commitment)
+}
+
+impl Proof {
+    /// Encodes this proof, paired with the `seed` it was generated with, into
+    /// a compact versioned binary buffer: a one-byte format version, the
+    /// commitment root, `coset_log_size`, `log_size_bound`, the FRI
+    /// configuration, the proof-of-work nonce, the optional seed, and the
+    /// queried evaluations as a length-prefixed vector of fixed-width
+    /// little-endian `M31` words (four per `QM31` evaluation).
+    ///
+    /// Query positions aren't stored since [`crate::proof::get_queries_from_proof`]
+    /// re-derives them deterministically from the full proof (its FRI layer
+    /// commitments and proof-of-work nonce) and seed.
+    pub fn to_bytes(&self, seed: Option<u64>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.push(WIRE_FORMAT_VERSION);
+        bytes.extend_from_slice(&self.proof.first_layer.commitment.0);
+        bytes.extend_from_slice(&self.coset_log_size.to_le_bytes());
+        bytes.extend_from_slice(&self.log_size_bound.to_le_bytes());
+        bytes.extend_from_slice(&self.pcs_config.fri_config.log_blowup_factor.to_le_bytes());
+        bytes.extend_from_slice(
+            &self.pcs_config.fri_config.log_last_layer_degree_bound.to_le_bytes(),
+        );
+        bytes.extend_from_slice(&(self.pcs_config.fri_config.n_queries as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.pcs_config.pow_bits.to_le_bytes());
+        bytes.extend_from_slice(&self.proof_of_work.to_le_bytes());
+        bytes.push(seed.is_some() as u8);
+        bytes.extend_from_slice(&seed.unwrap_or(0).to_le_bytes());
+        bytes.extend_from_slice(&(self.evaluations.len() as u32).to_le_bytes());
+        for eval in &self.evaluations {
+            for limb in [eval.0 .0 .0, eval.0 .1 .0, eval.1 .0 .0, eval.1 .1 .0] {
+                bytes.extend_from_slice(&limb.to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a buffer produced by [`Proof::to_bytes`] into a [`WireProof`],
+    /// rejecting truncated, over-long, or version-mismatched input
+    pub fn from_bytes(bytes: &[u8]) -> Result<WireProof, CodecError> {
+        let mut pos = 0;
+        let version = read_u8(bytes, &mut pos)?;
+        if version != WIRE_FORMAT_VERSION {
+            return Err(CodecError::UnsupportedVersion(version));
+        }
+
+        let mut commitment_root = [0u8; 32];
+        commitment_root.copy_from_slice(read_bytes(bytes, &mut pos, 32)?);
+
+        let coset_log_size = read_u32(bytes, &mut pos)?;
+        let log_size_bound = read_u32(bytes, &mut pos)?;
+        let log_blowup_factor = read_u32(bytes, &mut pos)?;
+        let log_last_layer_degree_bound = read_u32(bytes, &mut pos)?;
+        let n_queries = read_u32(bytes, &mut pos)?;
+        let pow_bits = read_u32(bytes, &mut pos)?;
+        let proof_of_work = read_u64(bytes, &mut pos)?;
+        let has_seed = read_u8(bytes, &mut pos)? != 0;
+        let seed_value = read_u64(bytes, &mut pos)?;
+        let seed = has_seed.then_some(seed_value);
+
+        let num_evaluations = read_u32(bytes, &mut pos)? as usize;
+        let mut evaluations = Vec::with_capacity(num_evaluations);
+        for _ in 0..num_evaluations {
+            let limbs = [
+                read_u32(bytes, &mut pos)?,
+                read_u32(bytes, &mut pos)?,
+                read_u32(bytes, &mut pos)?,
+                read_u32(bytes, &mut pos)?,
+            ];
+            evaluations.push(QM31(
+                CM31(
+                    M31::from_u32_unchecked(limbs[0]),
+                    M31::from_u32_unchecked(limbs[1]),
+                ),
+                CM31(
+                    M31::from_u32_unchecked(limbs[2]),
+                    M31::from_u32_unchecked(limbs[3]),
+                ),
+            ));
+        }
+
+        if pos != bytes.len() {
+            return Err(CodecError::TooLong);
+        }
+
+        Ok(WireProof {
+            commitment_root,
+            coset_log_size,
+            log_size_bound,
+            log_blowup_factor,
+            log_last_layer_degree_bound,
+            n_queries,
+            pow_bits,
+            proof_of_work,
+            seed,
+            evaluations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stwo_prover::core::{fri::FriConfig, pcs::PcsConfig};
+
+    use super::*;
+    use crate::proof::generate_proof;
+
+    const PCS_CONFIG: PcsConfig = PcsConfig {
+        fri_config: FriConfig {
+            log_blowup_factor: 4,
+            log_last_layer_degree_bound: 1,
+            n_queries: 20,
+        },
+        pow_bits: 20,
+    };
+
+    #[test]
+    fn test_commitment_round_trips() {
+        let commitment: Commitment = [7u8; 32];
+        let bytes = commitment_to_bytes(&commitment);
+        assert_eq!(commitment_from_bytes(&bytes).unwrap(), commitment);
+    }
+
+    #[test]
+    fn test_commitment_from_bytes_rejects_truncated_input() {
+        let commitment: Commitment = [7u8; 32];
+        let mut bytes = commitment_to_bytes(&commitment);
+        bytes.pop();
+        assert_eq!(commitment_from_bytes(&bytes), Err(CodecError::Truncated));
+    }
+
+    #[test]
+    fn test_commitment_from_bytes_rejects_unsupported_version() {
+        let commitment: Commitment = [7u8; 32];
+        let mut bytes = commitment_to_bytes(&commitment);
+        bytes[0] = 99;
+        assert_eq!(
+            commitment_from_bytes(&bytes),
+            Err(CodecError::UnsupportedVersion(99))
+        );
+    }
+
+    #[test]
+    fn test_proof_round_trips() {
+        let data = include_bytes!("../blob");
+        let proof = generate_proof(data, Some(5), PCS_CONFIG);
+
+        let bytes = proof.to_bytes(Some(5));
+        let decoded = Proof::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.commitment_root, proof.proof.first_layer.commitment.0);
+        assert_eq!(decoded.coset_log_size, proof.coset_log_size);
+        assert_eq!(decoded.log_size_bound, proof.log_size_bound);
+        assert_eq!(decoded.proof_of_work, proof.proof_of_work);
+        assert_eq!(decoded.seed, Some(5));
+        assert_eq!(decoded.evaluations, proof.evaluations);
+    }
+
+    #[test]
+    fn test_proof_from_bytes_rejects_truncated_input() {
+        let data = include_bytes!("../blob");
+        let proof = generate_proof(data, None, PCS_CONFIG);
+
+        let mut bytes = proof.to_bytes(None);
+        bytes.pop();
+        assert_eq!(Proof::from_bytes(&bytes), Err(CodecError::Truncated));
+    }
+
+    #[test]
+    fn test_proof_from_bytes_rejects_over_long_input() {
+        let data = include_bytes!("../blob");
+        let proof = generate_proof(data, None, PCS_CONFIG);
+
+        let mut bytes = proof.to_bytes(None);
+        bytes.push(0);
+        assert_eq!(Proof::from_bytes(&bytes), Err(CodecError::TooLong));
+    }
+}
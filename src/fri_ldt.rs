@@ -0,0 +1,483 @@
+//! Native FRI low-degree test module
+//!
+//! [`crate::fri`] folds with a general `fan_in` via Lagrange interpolation at
+//! every round. This module implements the simpler, textbook specialization
+//! of that recurrence: `fan_in` fixed at 2, folding driven directly off the
+//! even/odd coefficient split (the same decomposition underlying
+//! [`crate::polynomial::fft`]'s butterflies) rather than through a general
+//! interpolation step, and with the final layer sent as raw coefficients
+//! instead of a further Merkle-committed evaluation vector.
+//!
+//! Given `f`'s coefficients split into `f_even`/`f_odd` so that
+//! `f(x) = f_even(x^2) + x * f_odd(x^2)`, a verifier challenge `beta` folds
+//! `f` into the half-degree polynomial `f'(y) = f_even(y) + beta * f_odd(y)`.
+//! Evaluated over the domain, this is exactly
+//! `f'(x^2) = (f(x) + f(-x)) / 2 + beta * (f(x) - f(-x)) / (2x)`, which is
+//! what the query phase below checks at every round.
+
+use crate::{
+    field::get_primitive_root_of_unity,
+    polynomial,
+    transcript::Transcript,
+    utils::{self, MerkleTree},
+    FriedaError, Result, M31,
+};
+
+/// Domain separator absorbed by the folding-challenge transcript
+const FOLDING_CHALLENGES_DOMAIN_SEPARATOR: &[u8] = b"FRIEDA_FRI_LDT_FOLDING_CHALLENGES";
+/// Domain separator absorbed by the query-index transcript
+const QUERY_INDICES_DOMAIN_SEPARATOR: &[u8] = b"FRIEDA_FRI_LDT_QUERY_INDICES";
+
+/// One round's authenticated opening of a query: the `f(x)`/`f(-x)` pair at
+/// that round's folded index, together with their Merkle authentication
+/// paths against that round's layer root
+#[derive(Debug, Clone)]
+pub struct LdtOpening {
+    /// The index within this round's (halved) domain that `value_pos` sits
+    /// at; `value_neg` sits at `index + (domain_size / 2)`
+    pub index: usize,
+    /// The opened value `f(x)`
+    pub value_pos: M31,
+    /// The opened value `f(-x)`
+    pub value_neg: M31,
+    /// The Merkle authentication path proving `value_pos` is the leaf at `index`
+    pub auth_path_pos: Vec<[u8; 32]>,
+    /// The Merkle authentication path proving `value_neg` is the leaf at `index + (domain_size / 2)`
+    pub auth_path_neg: Vec<[u8; 32]>,
+}
+
+/// Everything the verifier needs to recheck one query's folding chain, round
+/// by round, down to the final layer
+#[derive(Debug, Clone)]
+pub struct QueryOpenings {
+    /// The query index into the round 0 (originally committed) domain,
+    /// drawn over the domain's first half since a query opens an `(x, -x)`
+    /// pair rather than a single point
+    pub index: usize,
+    /// One opening per folding round, in folding order
+    pub round_openings: Vec<LdtOpening>,
+}
+
+/// A native FRI low-degree test proof
+#[derive(Debug, Clone)]
+pub struct FriLdtProof {
+    /// The Merkle root of every folded layer's evaluations, in folding order
+    pub layer_roots: Vec<[u8; 32]>,
+    /// The per-query folding chains
+    pub query_openings: Vec<QueryOpenings>,
+    /// The coefficients of the final, sub-bound polynomial, sent in the
+    /// clear rather than Merkle-committed
+    pub final_layer: Vec<M31>,
+}
+
+/// Splits `f`'s coefficients into even/odd halves and folds them into
+/// `f'(y) = f_even(y) + beta * f_odd(y)`
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients of `f`, in ascending order of degree
+/// * `beta` - The folding challenge
+///
+/// # Returns
+///
+/// The coefficients of the half-degree folded polynomial `f'`
+fn fold_coefficients(coeffs: &[M31], beta: M31) -> Vec<M31> {
+    coeffs
+        .chunks(2)
+        .map(|pair| pair[0] + beta * pair[1])
+        .collect()
+}
+
+/// Computes the number of folding rounds needed to bring a polynomial of
+/// `degree_bound` coefficients down to at most `last_layer_len` coefficients
+fn num_rounds(degree_bound: usize, last_layer_len: usize) -> usize {
+    let mut dimension = degree_bound;
+    let mut rounds = 0;
+    while dimension > last_layer_len {
+        dimension /= 2;
+        rounds += 1;
+    }
+    rounds
+}
+
+/// Derives the query indices a prover and verifier both open, by absorbing
+/// every committed layer root into a fresh Fiat-Shamir transcript
+///
+/// # Arguments
+///
+/// * `layer_roots` - The Merkle roots of every committed layer, in folding order
+/// * `domain_size` - The size of the round 0 evaluation domain
+/// * `num_queries` - The number of queries to make
+///
+/// # Returns
+///
+/// A vector of query indices in `[0, domain_size / 2)`, pseudo-random but
+/// re-derivable by anyone who knows `layer_roots`
+fn derive_query_indices(layer_roots: &[[u8; 32]], domain_size: usize, num_queries: usize) -> Vec<usize> {
+    let mut transcript = Transcript::new(QUERY_INDICES_DOMAIN_SEPARATOR);
+    for root in layer_roots {
+        transcript.absorb(root);
+    }
+    transcript.squeeze_indices(num_queries, domain_size / 2)
+}
+
+/// Derives the per-round folding challenges, absorbing each layer's root
+/// before squeezing the challenge it is folded by
+///
+/// # Arguments
+///
+/// * `layer_roots` - The Merkle roots of every committed layer, in folding order
+///
+/// # Returns
+///
+/// One challenge per committed layer, re-derivable by anyone who knows `layer_roots`
+fn derive_folding_challenges(layer_roots: &[[u8; 32]]) -> Vec<M31> {
+    let mut transcript = Transcript::new(FOLDING_CHALLENGES_DOMAIN_SEPARATOR);
+    layer_roots
+        .iter()
+        .map(|root| {
+            transcript.absorb(root);
+            transcript.squeeze_challenge()
+        })
+        .collect()
+}
+
+/// Opens one query's full folding chain against the already-committed layers
+///
+/// # Arguments
+///
+/// * `index` - The query index into the round 0 domain's first half
+/// * `layer_evaluations` - Every committed layer's evaluations, in folding order
+/// * `layer_trees` - Every committed layer's Merkle tree, in folding order
+///
+/// # Returns
+///
+/// The per-round openings for this query
+fn open_query(
+    index: usize,
+    layer_evaluations: &[Vec<M31>],
+    layer_trees: &[MerkleTree],
+) -> Result<Vec<LdtOpening>> {
+    let mut round_openings = Vec::with_capacity(layer_trees.len());
+    let mut current_idx = index;
+
+    for (evaluations, tree) in layer_evaluations.iter().zip(layer_trees) {
+        let half = evaluations.len() / 2;
+        let i = current_idx % half;
+
+        round_openings.push(LdtOpening {
+            index: i,
+            value_pos: evaluations[i],
+            value_neg: evaluations[i + half],
+            auth_path_pos: tree.get_auth_path(i)?,
+            auth_path_neg: tree.get_auth_path(i + half)?,
+        });
+
+        current_idx = i;
+    }
+
+    Ok(round_openings)
+}
+
+/// FRI prover for generating low-degree test proofs over the `polynomial` module's M31 polynomials
+#[derive(Debug)]
+pub struct FriLdtProver {
+    /// The log2 of the evaluation domain's blowup over the polynomial's degree bound
+    log_blowup_factor: u32,
+    /// The log2 of the coefficient count the final layer must be at or below
+    log_last_layer_degree_bound: u32,
+    /// The number of queries to make
+    num_queries: usize,
+}
+
+impl FriLdtProver {
+    /// Creates a new FRI low-degree test prover
+    ///
+    /// # Arguments
+    ///
+    /// * `log_blowup_factor` - The log2 of the evaluation domain's blowup over the polynomial's degree bound
+    /// * `log_last_layer_degree_bound` - The log2 of the coefficient count the final layer must be at or below
+    /// * `num_queries` - The number of queries to make
+    ///
+    /// # Returns
+    ///
+    /// A new FRI low-degree test prover
+    pub fn new(log_blowup_factor: u32, log_last_layer_degree_bound: u32, num_queries: usize) -> Self {
+        Self {
+            log_blowup_factor,
+            log_last_layer_degree_bound,
+            num_queries,
+        }
+    }
+
+    /// Proves that `coeffs` is the coefficient vector of a low-degree polynomial
+    ///
+    /// # Arguments
+    ///
+    /// * `coeffs` - The coefficients of the polynomial, in ascending order of degree
+    ///
+    /// # Returns
+    ///
+    /// A FRI low-degree test proof
+    pub fn prove(&self, coeffs: &[M31]) -> Result<FriLdtProof> {
+        if coeffs.is_empty() {
+            return Err(FriedaError::InvalidInput(
+                "Polynomial must have at least one coefficient".to_string(),
+            ));
+        }
+
+        let degree_bound = coeffs.len().next_power_of_two();
+        let last_layer_len = 1usize << self.log_last_layer_degree_bound;
+        if degree_bound < last_layer_len {
+            return Err(FriedaError::InvalidInput(
+                "Polynomial degree bound must be at least the last layer degree bound".to_string(),
+            ));
+        }
+
+        let mut current_coeffs = coeffs.to_vec();
+        current_coeffs.resize(degree_bound, M31::default());
+        let mut current_domain_size = degree_bound << self.log_blowup_factor;
+        let round_0_domain_size = current_domain_size;
+
+        let mut layer_roots = Vec::new();
+        let mut layer_evaluations = Vec::new();
+        let mut layer_trees = Vec::new();
+        let mut transcript = Transcript::new(FOLDING_CHALLENGES_DOMAIN_SEPARATOR);
+
+        while current_coeffs.len() > last_layer_len {
+            let evaluations = polynomial::fft(current_coeffs.clone(), current_domain_size)?;
+            let tree = utils::create_merkle_tree(&evaluations);
+            let root = tree.root();
+            layer_roots.push(root);
+            layer_evaluations.push(evaluations);
+            layer_trees.push(tree);
+
+            transcript.absorb(&root);
+            let beta = transcript.squeeze_challenge();
+            current_coeffs = fold_coefficients(&current_coeffs, beta);
+            current_domain_size /= 2;
+        }
+
+        let query_indices = derive_query_indices(&layer_roots, round_0_domain_size, self.num_queries);
+        let query_openings = query_indices
+            .into_iter()
+            .map(|index| {
+                open_query(index, &layer_evaluations, &layer_trees)
+                    .map(|round_openings| QueryOpenings { index, round_openings })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(FriLdtProof {
+            layer_roots,
+            query_openings,
+            final_layer: current_coeffs,
+        })
+    }
+}
+
+/// FRI verifier for checking low-degree test proofs produced by [`FriLdtProver`]
+#[derive(Debug)]
+pub struct FriLdtVerifier {
+    /// The log2 of the evaluation domain's blowup over the polynomial's degree bound
+    log_blowup_factor: u32,
+    /// The log2 of the coefficient count the final layer must be at or below
+    log_last_layer_degree_bound: u32,
+}
+
+impl FriLdtVerifier {
+    /// Creates a new FRI low-degree test verifier
+    ///
+    /// # Arguments
+    ///
+    /// * `log_blowup_factor` - The log2 of the evaluation domain's blowup over the polynomial's degree bound
+    /// * `log_last_layer_degree_bound` - The log2 of the coefficient count the final layer must be at or below
+    ///
+    /// # Returns
+    ///
+    /// A new FRI low-degree test verifier
+    pub fn new(log_blowup_factor: u32, log_last_layer_degree_bound: u32) -> Self {
+        Self {
+            log_blowup_factor,
+            log_last_layer_degree_bound,
+        }
+    }
+
+    /// Verifies that `proof` attests to a polynomial below `degree_bound` in degree
+    ///
+    /// # Arguments
+    ///
+    /// * `degree_bound` - The claimed degree bound of the tested polynomial
+    /// * `proof` - The FRI low-degree test proof
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the proof is valid, otherwise an `Err` describing which check failed
+    pub fn verify(&self, degree_bound: usize, proof: &FriLdtProof) -> Result<()> {
+        let degree_bound = degree_bound.next_power_of_two();
+        let last_layer_len = 1usize << self.log_last_layer_degree_bound;
+        let domain_size = degree_bound << self.log_blowup_factor;
+
+        if proof.layer_roots.len() != num_rounds(degree_bound, last_layer_len) {
+            return Err(FriedaError::VerificationFailed(
+                "Unexpected number of FRI layers".to_string(),
+            ));
+        }
+        if proof.final_layer.len() > last_layer_len {
+            return Err(FriedaError::VerificationFailed(
+                "Final layer exceeds the claimed degree bound".to_string(),
+            ));
+        }
+
+        let betas = derive_folding_challenges(&proof.layer_roots);
+        let expected_indices =
+            derive_query_indices(&proof.layer_roots, domain_size, proof.query_openings.len());
+
+        for (opening, &expected_index) in proof.query_openings.iter().zip(&expected_indices) {
+            if opening.index != expected_index {
+                return Err(FriedaError::VerificationFailed(
+                    "Query index does not match Fiat-Shamir derivation".to_string(),
+                ));
+            }
+            if opening.round_openings.len() != proof.layer_roots.len() {
+                return Err(FriedaError::VerificationFailed(
+                    "Query is missing round openings".to_string(),
+                ));
+            }
+
+            self.verify_query(domain_size, opening, &betas, proof)?;
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a single query's Merkle authentication and fold consistency,
+    /// round by round, down to the final layer
+    fn verify_query(
+        &self,
+        round_0_domain_size: usize,
+        opening: &QueryOpenings,
+        betas: &[M31],
+        proof: &FriLdtProof,
+    ) -> Result<()> {
+        let mut current_idx = opening.index;
+        let mut current_domain_size = round_0_domain_size;
+        let mut folded_value = None;
+
+        for ((round_opening, &beta), root) in opening
+            .round_openings
+            .iter()
+            .zip(betas)
+            .zip(&proof.layer_roots)
+        {
+            let half = current_domain_size / 2;
+            let i = current_idx % half;
+            if round_opening.index != i {
+                return Err(FriedaError::VerificationFailed(
+                    "Round opening index does not match the folded query index".to_string(),
+                ));
+            }
+
+            let leaf_pos = utils::hash(&utils::m31_to_bytes(round_opening.value_pos));
+            let leaf_neg = utils::hash(&utils::m31_to_bytes(round_opening.value_neg));
+            if !MerkleTree::verify_inclusion(&leaf_pos, i, &round_opening.auth_path_pos, root)
+                || !MerkleTree::verify_inclusion(&leaf_neg, i + half, &round_opening.auth_path_neg, root)
+            {
+                return Err(FriedaError::VerificationFailed(
+                    "Merkle authentication failed".to_string(),
+                ));
+            }
+
+            if let Some(expected) = folded_value {
+                // The previous round's fold lands at `current_idx` in this
+                // layer, which is the `-x` side of the pair once it is at or
+                // past `half`
+                let opened = if current_idx < half {
+                    round_opening.value_pos
+                } else {
+                    round_opening.value_neg
+                };
+                if opened != expected {
+                    return Err(FriedaError::VerificationFailed(
+                        "Fold is inconsistent with the previous round's opening".to_string(),
+                    ));
+                }
+            }
+
+            // f'(x^2) = (f(x) + f(-x)) / 2 + beta * (f(x) - f(-x)) / (2x)
+            let omega = get_primitive_root_of_unity(current_domain_size);
+            let x = omega.pow(i as u128);
+            let two = M31::from(2u32);
+            folded_value = Some(
+                (round_opening.value_pos + round_opening.value_neg) / two
+                    + beta * (round_opening.value_pos - round_opening.value_neg) / (two * x),
+            );
+
+            current_idx = i;
+            current_domain_size = half;
+        }
+
+        if let Some(folded_value) = folded_value {
+            let point = get_primitive_root_of_unity(current_domain_size).pow(current_idx as u128);
+            let expected_final = polynomial::evaluate_polynomial(&proof.final_layer, point);
+            if folded_value != expected_final {
+                return Err(FriedaError::VerificationFailed(
+                    "Final layer does not match the last round's fold".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_polynomial() -> Vec<M31> {
+        // 5 + x + 2x^2 + 3x^3 + x^4 + 4x^5 + 2x^6 + x^7
+        [5, 1, 2, 3, 1, 4, 2, 1].into_iter().map(M31::from).collect()
+    }
+
+    #[test]
+    fn test_prove_and_verify_accepts_honest_proof() {
+        let coeffs = test_polynomial();
+        let prover = FriLdtProver::new(2, 1, 8);
+        let proof = prover.prove(&coeffs).unwrap();
+
+        let verifier = FriLdtVerifier::new(2, 1);
+        assert!(verifier.verify(coeffs.len(), &proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_final_layer() {
+        let coeffs = test_polynomial();
+        let prover = FriLdtProver::new(2, 1, 8);
+        let mut proof = prover.prove(&coeffs).unwrap();
+        proof.final_layer[0] += M31::from(1u32);
+
+        let verifier = FriLdtVerifier::new(2, 1);
+        assert!(verifier.verify(coeffs.len(), &proof).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_query_opening() {
+        let coeffs = test_polynomial();
+        let prover = FriLdtProver::new(2, 1, 8);
+        let mut proof = prover.prove(&coeffs).unwrap();
+        proof.query_openings[0].round_openings[0].value_pos += M31::from(1u32);
+
+        let verifier = FriLdtVerifier::new(2, 1);
+        assert!(verifier.verify(coeffs.len(), &proof).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_degree_bound() {
+        let coeffs = test_polynomial();
+        let prover = FriLdtProver::new(2, 1, 8);
+        let proof = prover.prove(&coeffs).unwrap();
+
+        let verifier = FriLdtVerifier::new(2, 1);
+        assert!(verifier.verify(coeffs.len() / 2, &proof).is_err());
+    }
+}
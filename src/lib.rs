@@ -11,74 +11,210 @@
 /// Re-export of stwo-prover's M31 field for arithmetic operations
 pub use stwo_prover::core::fields::m31::M31;
 
+use fri::{FriLayer, QueryInfo};
+
+/// Errors shared across FRIEDA's hand-rolled FRI/PCS machinery (`da`,
+/// `fri`, `fri_ldt`, `pcs`, `polynomial`, `sampling`, `vid`) and the wire
+/// codecs (`calldata`), carrying a human-readable description of what went
+/// wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FriedaError {
+    /// Decoding or reconstructing data from samples/evaluations failed
+    DecodingError(String),
+    /// An argument was malformed or out of range for the operation
+    InvalidInput(String),
+    /// A Merkle authentication path failed to verify, or had the wrong shape
+    InvalidMerklePath(String),
+    /// A FRI proof (or a verification step built on one) did not check out
+    VerificationFailed(String),
+}
+
+impl std::fmt::Display for FriedaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FriedaError::DecodingError(msg) => write!(f, "decoding error: {msg}"),
+            FriedaError::InvalidInput(msg) => write!(f, "invalid input: {msg}"),
+            FriedaError::InvalidMerklePath(msg) => write!(f, "invalid Merkle path: {msg}"),
+            FriedaError::VerificationFailed(msg) => write!(f, "verification failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FriedaError {}
+
+/// Convenience alias for a [`Result`](std::result::Result) whose error type
+/// is [`FriedaError`], used throughout the hand-rolled FRI/PCS modules
+pub type Result<T> = std::result::Result<T, FriedaError>;
+
+/// A commitment to data erasure-coded and Merkle-committed by [`da::commit`]
+/// (or [`vid::disperse`]), carrying the metadata a verifier needs to
+/// reconstruct the same FRI parameters the prover used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Commitment {
+    /// The Merkle root of the committed codeword
+    pub root: [u8; 32],
+    /// The parameters the codeword was encoded and committed under
+    pub metadata: CommitmentMetadata,
+}
+
+/// Parameters a [`Commitment`] was produced under, needed to reconstruct a
+/// matching [`fri::FriProver`]/[`fri::FriVerifier`] or to recover the
+/// original message length during reconstruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitmentMetadata {
+    /// The Reed-Solomon-encoded evaluation domain size
+    pub domain_size: usize,
+    /// The expansion factor (inverse rate) the data was encoded under
+    pub expansion_factor: usize,
+    /// The batch size for batched FRI
+    pub batch_size: usize,
+    /// The field size in bits
+    pub field_size: usize,
+    /// The exact bit length of the original (pre-encoding) data
+    pub bit_length: usize,
+}
+
+/// A FRI low-degree proof produced by [`fri::FriProver`], opening every
+/// query's full folding chain down to the final layer
+#[derive(Debug, Clone)]
+pub struct FriProof {
+    /// Every queried index's authenticated folding chain
+    pub query_info: Vec<QueryInfo>,
+    /// The last folded layer's evaluations (the committed evaluations
+    /// themselves when there are no folding rounds)
+    pub final_layer: Vec<M31>,
+    /// The per-round folding challenges the prover folded with
+    pub betas: Vec<M31>,
+    /// The intermediate layers produced while folding
+    pub layers: Vec<FriLayer>,
+    /// The proof-of-work nonce ground against the commitment and layer
+    /// roots, and absorbed into the query-index transcript
+    pub pow_nonce: u64,
+}
+
+/// The result of sampling a [`Commitment`]'s codeword at a set of positions,
+/// produced by [`sampling::sample`] and checked by [`sampling::verify_sampling`]
+#[derive(Debug, Clone)]
+pub struct SampleResult {
+    /// Whether sampling completed successfully
+    pub success: bool,
+    /// The sampled codeword values, in the same order as `indices`
+    pub values: Vec<M31>,
+    /// The sampled domain indices
+    pub indices: Vec<usize>,
+    /// Per-sample Merkle authentication paths against the commitment root,
+    /// in the same order as `indices`
+    pub proofs: Vec<Vec<[u8; 32]>>,
+    /// The client-supplied nonce the sampled indices were derived from
+    pub nonce: u64,
+    /// The proof-of-work nonce that unlocked those indices
+    pub proof_of_work: u64,
+}
+
 // Define library modules
+pub mod calldata;
+pub mod codec;
 pub mod commit;
+pub mod da;
+pub mod field;
+pub mod fri;
+pub mod fri_ldt;
+pub mod pcs;
+pub mod polynomial;
 pub mod proof;
 pub mod reconstruct;
+pub mod sampling;
+pub mod transcript;
 pub mod utils;
+pub mod vid;
 
 /// Core public API for FRIEDA
 pub mod api {
 
-    use std::collections::HashSet;
+    use stwo_prover::core::pcs::PcsConfig;
 
-    use stwo_prover::core::{
-        circle::Coset, pcs::PcsConfig, poly::circle::CircleDomain, utils::bit_reverse_index,
+    use crate::{
+        commit::Commitment,
+        proof::{BatchProof, HidingProof, Proof},
+        reconstruct::{checked_reconstruct, ReconstructError},
     };
 
-    use crate::{commit::Commitment, proof::Proof, reconstruct::get_queries_from_proof};
-
     use super::*;
 
+    /// A commitment to several blobs batched under a single Merkle tree, as
+    /// returned by [`commit_batch`]
+    pub type BatchCommitment = Commitment;
+
     /// Commit to data using FRI protocol
     pub fn commit(data: &[u8], log_blowup_factor: u32) -> Commitment {
         commit::commit(data, log_blowup_factor)
     }
 
+    /// Commits to several blobs at once under a single Merkle tree, so a
+    /// single FRI proof generated by [`generate_batch_proof`] can later
+    /// cover all of them
+    pub fn commit_batch(blobs: &[&[u8]], log_blowup_factor: u32) -> BatchCommitment {
+        commit::batch_commit(blobs, log_blowup_factor)
+    }
+
     /// Generate a FRI proof for committed data
     pub fn generate_proof(data: &[u8], seed: Option<u64>, pcs_config: PcsConfig) -> Proof {
         proof::generate_proof(data, seed, pcs_config)
     }
 
+    /// Generates a single FRI proof covering several blobs at once
+    ///
+    /// Every blob is folded through the same set of FRI layers and a single
+    /// proof-of-work grind, rather than paying for `blobs.len()` independent
+    /// proofs; [`verify_batch`] checks all of them together from the result.
+    pub fn generate_batch_proof(
+        blobs: &[&[u8]],
+        seed: Option<u64>,
+        pcs_config: PcsConfig,
+    ) -> BatchProof {
+        proof::batch_generate_proof(blobs, seed, pcs_config)
+    }
+
     /// Verify a FRI proof against a commitment
     pub fn verify(proof: Proof, seed: Option<u64>) -> bool {
         proof::verify_proof(proof, seed)
     }
 
-    /// Reconstruct the original data from a list of proofs
-    pub fn reconstruct(proofs: Vec<Proof>) -> Vec<u8> {
-        let coset = Coset::half_odds(proofs[0].coset_log_size);
-        let poly_log_size = proofs[0].log_size_bound;
-        let pos_evals = proofs
-            .into_iter()
-            .map(|p| (get_queries_from_proof(p.clone(), p.seed), p.evaluations))
-            .collect::<Vec<_>>();
-        let domain = CircleDomain::new(coset);
-        let mut pos_set = HashSet::new();
-        let mut xs = Vec::with_capacity(1 << domain.log_size());
-        let mut evals_vec = Vec::with_capacity(1 << domain.log_size());
-        for ((_, pos), evals) in pos_evals {
-            for (i, p) in pos.iter().enumerate() {
-                let point = domain.at(bit_reverse_index(*p, domain.log_size()));
-                if pos_set.insert(point) {
-                    xs.push(point);
-                    evals_vec.push(evals[i]);
-                }
-            }
-        }
-        let interpolated_poly = reconstruct::fast_circle_interpolation(
-            &xs[..(1 << poly_log_size) + 1],
-            &evals_vec[..(1 << poly_log_size) + 1],
-        );
-        let interpolated = interpolated_poly.0[0]
-            .coeffs
-            .iter()
-            .zip(&interpolated_poly.0[1].coeffs)
-            .zip(&interpolated_poly.0[2].coeffs)
-            .zip(&interpolated_poly.0[3].coeffs)
-            .flat_map(|(((a, b), c), d)| [a, b, c, d])
-            .collect::<Vec<&M31>>();
-        utils::felts_to_bytes_le(&interpolated)
+    /// Generates a FRI proof in hiding mode: the committed evaluations are
+    /// blinded by a random masking polynomial derived from `hiding_seed`, so
+    /// a single light client's sampled evaluations don't leak the
+    /// underlying data; see [`crate::proof::unmask_hiding_evaluations`] to
+    /// recover the true data evaluations once the seed is public.
+    pub fn generate_hiding_proof(
+        data: &[u8],
+        seed: Option<u64>,
+        hiding_seed: u64,
+        pcs_config: PcsConfig,
+    ) -> HidingProof {
+        proof::generate_hiding_proof(data, seed, hiding_seed, pcs_config)
+    }
+
+    /// Verifies a hiding-mode proof produced by [`generate_hiding_proof`]
+    pub fn verify_hiding_proof(proof: HidingProof, seed: Option<u64>) -> bool {
+        proof::verify_hiding_proof(proof, seed)
+    }
+
+    /// Verifies a batched FRI proof produced by [`generate_batch_proof`]
+    pub fn verify_batch(proof: BatchProof, seed: Option<u64>) -> bool {
+        proof::batch_verify_proof(proof, seed)
+    }
+
+    /// Reconstructs the original data from a list of `(proof, seed)` pairs
+    ///
+    /// Any quorum of distinct sampled points at least as large as the
+    /// degree bound requires suffices, regardless of which proof
+    /// contributed them; see [`checked_reconstruct`] for the erasure-decode
+    /// details and the errors returned when a quorum is insufficient or
+    /// inconsistent.
+    pub fn reconstruct(
+        proofs: Vec<(Proof, Option<u64>)>,
+    ) -> std::result::Result<Vec<u8>, ReconstructError> {
+        checked_reconstruct(proofs)
     }
 }
 
@@ -122,4 +258,54 @@ mod tests {
         let verification_result = api::verify(proof, None);
         assert!(verification_result);
     }
+
+    #[test]
+    fn test_batch_end_to_end() {
+        let blob_a = b"This is the first blob that needs to be made available.";
+        let blob_b = b"A second, shorter blob batched alongside the first one.";
+        let blobs: [&[u8]; 2] = [blob_a, blob_b];
+
+        let commitment = api::commit_batch(&blobs, 4);
+
+        let proof = api::generate_batch_proof(
+            &blobs,
+            None,
+            PcsConfig {
+                fri_config: FriConfig {
+                    log_blowup_factor: 4,
+                    log_last_layer_degree_bound: 0,
+                    n_queries: 20,
+                },
+                pow_bits: 20,
+            },
+        );
+        assert_eq!(proof.num_blobs, blobs.len());
+
+        assert!(api::verify_batch(proof, None));
+        assert_eq!(
+            commitment,
+            crate::commit::batch_commit(&blobs, 4)
+        );
+    }
+
+    #[test]
+    fn test_hiding_proof_end_to_end() {
+        let original_data = b"This is the original data that needs to be made available.";
+
+        let proof = api::generate_hiding_proof(
+            original_data,
+            None,
+            42,
+            PcsConfig {
+                fri_config: FriConfig {
+                    log_blowup_factor: 4,
+                    log_last_layer_degree_bound: 0,
+                    n_queries: 20,
+                },
+                pow_bits: 20,
+            },
+        );
+
+        assert!(api::verify_hiding_proof(proof, None));
+    }
 }
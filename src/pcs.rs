@@ -0,0 +1,297 @@
+//! Polynomial commitment scheme module
+//!
+//! Builds a minimal FRI-PCS on top of [`crate::fri_ldt`]'s native low-degree
+//! test: opening a commitment to `f` at a point `z` reduces to low-degree
+//! testing the quotient `q(x) = (f(x) - y) / (x - z)`. Since `z` is a root
+//! of `f(x) - y` whenever `y = f(z)`, this division is exact and `q` has
+//! degree one less than `f`; proving `q` is low-degree is what convinces a
+//! verifier the claimed evaluation is genuine rather than fabricated.
+
+use crate::{
+    field::get_primitive_root_of_unity,
+    fri_ldt::{FriLdtProof, FriLdtProver, FriLdtVerifier},
+    polynomial,
+    utils::{self, MerkleTree},
+    FriedaError, Result, M31,
+};
+
+/// The log2 of the evaluation domain's blowup over a polynomial's degree
+/// bound, shared by every commitment and opening this module produces
+const LOG_BLOWUP_FACTOR: u32 = 2;
+/// The log2 of the coefficient count the FRI LDT's final layer must be at or below
+const LOG_LAST_LAYER_DEGREE_BOUND: u32 = 1;
+/// The number of FRI queries made per opening
+const NUM_QUERIES: usize = 32;
+
+/// A commitment to a polynomial's coefficients under this module's PCS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PcsCommitment {
+    /// The Merkle root of the committed polynomial's evaluations
+    pub root: [u8; 32],
+    /// The number of coefficients the committed polynomial carries; needed
+    /// to reconstruct the FRI parameters an opening proof was produced under
+    pub degree_bound: usize,
+}
+
+/// One query's authenticated opening of `f(x)`/`f(-x)` against the
+/// commitment, paired by position with the inner quotient proof's own
+/// round 0 openings at the same domain indices
+#[derive(Debug, Clone)]
+pub struct FOpening {
+    /// The opened value `f(x)`
+    pub value_pos: M31,
+    /// The opened value `f(-x)`
+    pub value_neg: M31,
+    /// The Merkle authentication path proving `value_pos` is the leaf at the query's index
+    pub auth_path_pos: Vec<[u8; 32]>,
+    /// The Merkle authentication path proving `value_neg` is the leaf at the query's index plus half the domain
+    pub auth_path_neg: Vec<[u8; 32]>,
+}
+
+/// A proof that the polynomial committed to by a [`PcsCommitment`] evaluates
+/// to a claimed `y` at a point `z`
+#[derive(Debug, Clone)]
+pub struct OpeningProof {
+    /// The Merkle root of `f`'s own commitment, so the verifier can check
+    /// this proof was produced against the commitment it is being checked
+    /// against
+    pub f_commitment_root: [u8; 32],
+    /// Authenticated openings of `f` itself, in the same order as
+    /// `quotient_proof.query_openings`
+    pub f_openings: Vec<FOpening>,
+    /// The FRI low-degree test proof that `q(x) = (f(x) - y) / (x - z)` is
+    /// of degree `deg(f) - 1`
+    pub quotient_proof: FriLdtProof,
+}
+
+/// Divides `f(x) - y` by `x - z` via synthetic (Ruffini) division
+///
+/// `z` being a root of `f(x) - y` makes this division exact, so the
+/// remainder (the last value `acc` holds) is simply dropped rather than
+/// checked; a dishonest `y` shows up instead as a quotient that fails the
+/// FRI low-degree test, since it no longer agrees with `f` at every domain point.
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients of `f`, in ascending order of degree
+/// * `y` - The claimed evaluation `f(z)`
+/// * `z` - The point `f` is being opened at
+///
+/// # Returns
+///
+/// The coefficients of `q(x) = (f(x) - y) / (x - z)`, one degree lower than `f`
+fn synthetic_division(coeffs: &[M31], y: M31, z: M31) -> Vec<M31> {
+    let degree = coeffs.len() - 1;
+    let mut quotient = vec![M31::default(); degree];
+    let mut acc = M31::default();
+    for i in (0..coeffs.len()).rev() {
+        let c = if i == 0 { coeffs[0] - y } else { coeffs[i] };
+        acc = c + z * acc;
+        if i > 0 {
+            quotient[i - 1] = acc;
+        }
+    }
+    quotient
+}
+
+/// Commits to a polynomial's coefficients
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients of the polynomial, in ascending order of degree
+///
+/// # Returns
+///
+/// A commitment to the polynomial
+pub fn pcs_commit(coeffs: &[M31]) -> Result<PcsCommitment> {
+    if coeffs.is_empty() {
+        return Err(FriedaError::InvalidInput(
+            "Polynomial must have at least one coefficient".to_string(),
+        ));
+    }
+
+    let (_, tree) = commit_evaluations(coeffs)?;
+    Ok(PcsCommitment {
+        root: tree.root(),
+        degree_bound: coeffs.len(),
+    })
+}
+
+/// Evaluates a polynomial over its commitment domain and builds the Merkle
+/// tree committing to those evaluations, shared by [`pcs_commit`] and
+/// [`pcs_open`] so both derive the exact same commitment for the same polynomial
+fn commit_evaluations(coeffs: &[M31]) -> Result<(Vec<M31>, MerkleTree)> {
+    let degree_bound = coeffs.len().next_power_of_two();
+    let domain_size = degree_bound << LOG_BLOWUP_FACTOR;
+
+    let mut padded = coeffs.to_vec();
+    padded.resize(degree_bound, M31::default());
+    let evaluations = polynomial::fft(padded, domain_size)?;
+    let tree = utils::create_merkle_tree(&evaluations);
+    Ok((evaluations, tree))
+}
+
+/// Opens a committed polynomial at an arbitrary evaluation point
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients of the polynomial, in ascending order of degree
+/// * `z` - The point to open the polynomial at
+///
+/// # Returns
+///
+/// The claimed evaluation `y = f(z)` and the proof that it is genuine
+pub fn pcs_open(coeffs: &[M31], z: M31) -> Result<(M31, OpeningProof)> {
+    if coeffs.is_empty() {
+        return Err(FriedaError::InvalidInput(
+            "Polynomial must have at least one coefficient".to_string(),
+        ));
+    }
+    if coeffs.len() == 1 {
+        return Err(FriedaError::InvalidInput(
+            "Cannot open a constant polynomial: the quotient would have no coefficients".to_string(),
+        ));
+    }
+
+    let y = polynomial::evaluate_polynomial(coeffs, z);
+    let quotient_coeffs = synthetic_division(coeffs, y, z);
+
+    let (f_evaluations, f_tree) = commit_evaluations(coeffs)?;
+    let domain_size = coeffs.len().next_power_of_two() << LOG_BLOWUP_FACTOR;
+    let half = domain_size / 2;
+
+    let prover = FriLdtProver::new(LOG_BLOWUP_FACTOR, LOG_LAST_LAYER_DEGREE_BOUND, NUM_QUERIES);
+    let quotient_proof = prover.prove(&quotient_coeffs)?;
+
+    // `q` has one coefficient fewer than `f` but the same degree bound
+    // (rounding a power of two down by one coefficient and back up to the
+    // next power of two returns the same power of two, for any bound above
+    // 2), so the quotient proof's query indices land in `f`'s own domain.
+    let f_openings = quotient_proof
+        .query_openings
+        .iter()
+        .map(|opening| {
+            let i = opening.index;
+            Ok(FOpening {
+                value_pos: f_evaluations[i],
+                value_neg: f_evaluations[i + half],
+                auth_path_pos: f_tree.get_auth_path(i)?,
+                auth_path_neg: f_tree.get_auth_path(i + half)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((
+        y,
+        OpeningProof {
+            f_commitment_root: f_tree.root(),
+            f_openings,
+            quotient_proof,
+        },
+    ))
+}
+
+/// Verifies that the polynomial committed to by `commitment` evaluates to `y` at `z`
+///
+/// For every FRI query, re-derives the claimed quotient evaluations
+/// `q(x) = (f(x) - y) / (x - z)` from the authenticated `f` openings and
+/// checks them against what the inner FRI low-degree proof actually opened,
+/// before checking that proof itself.
+///
+/// # Arguments
+///
+/// * `commitment` - The commitment to the polynomial `f`
+/// * `z` - The point `f` is claimed to evaluate to `y` at
+/// * `y` - The claimed evaluation `f(z)`
+/// * `proof` - The opening proof
+///
+/// # Returns
+///
+/// `Ok(true)` if the opening is valid, `Ok(false)` otherwise
+pub fn pcs_verify(commitment: &PcsCommitment, z: M31, y: M31, proof: &OpeningProof) -> Result<bool> {
+    if proof.f_commitment_root != commitment.root {
+        return Ok(false);
+    }
+    if proof.f_openings.len() != proof.quotient_proof.query_openings.len() {
+        return Ok(false);
+    }
+    if commitment.degree_bound < 2 {
+        return Ok(false);
+    }
+
+    let domain_size = commitment.degree_bound.next_power_of_two() << LOG_BLOWUP_FACTOR;
+    let half = domain_size / 2;
+    let omega = get_primitive_root_of_unity(domain_size);
+
+    for (f_opening, query_opening) in proof.f_openings.iter().zip(&proof.quotient_proof.query_openings) {
+        let i = query_opening.index;
+
+        let leaf_pos = utils::hash(&utils::m31_to_bytes(f_opening.value_pos));
+        let leaf_neg = utils::hash(&utils::m31_to_bytes(f_opening.value_neg));
+        if !MerkleTree::verify_inclusion(&leaf_pos, i, &f_opening.auth_path_pos, &commitment.root)
+            || !MerkleTree::verify_inclusion(&leaf_neg, i + half, &f_opening.auth_path_neg, &commitment.root)
+        {
+            return Ok(false);
+        }
+
+        let Some(round_0) = query_opening.round_openings.first() else {
+            return Ok(false);
+        };
+
+        let x = omega.pow(i as u128);
+        let expected_quotient_pos = (f_opening.value_pos - y) / (x - z);
+        let expected_quotient_neg = (f_opening.value_neg - y) / (-x - z);
+        if round_0.value_pos != expected_quotient_pos || round_0.value_neg != expected_quotient_neg {
+            return Ok(false);
+        }
+    }
+
+    let verifier = FriLdtVerifier::new(LOG_BLOWUP_FACTOR, LOG_LAST_LAYER_DEGREE_BOUND);
+    Ok(verifier
+        .verify(commitment.degree_bound - 1, &proof.quotient_proof)
+        .is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_polynomial() -> Vec<M31> {
+        // 5 + x + 2x^2 + 3x^3 + x^4 + 4x^5 + 2x^6 + x^7
+        [5, 1, 2, 3, 1, 4, 2, 1].into_iter().map(M31::from).collect()
+    }
+
+    #[test]
+    fn test_open_and_verify_accepts_honest_proof() {
+        let coeffs = test_polynomial();
+        let commitment = pcs_commit(&coeffs).unwrap();
+        let z = M31::from(1234u32);
+
+        let (y, proof) = pcs_open(&coeffs, z).unwrap();
+        assert_eq!(y, polynomial::evaluate_polynomial(&coeffs, z));
+        assert!(pcs_verify(&commitment, z, y, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_evaluation() {
+        let coeffs = test_polynomial();
+        let commitment = pcs_commit(&coeffs).unwrap();
+        let z = M31::from(1234u32);
+
+        let (y, proof) = pcs_open(&coeffs, z).unwrap();
+        let wrong_y = y + M31::from(1u32);
+        assert!(!pcs_verify(&commitment, z, wrong_y, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_mismatched_commitment() {
+        let coeffs = test_polynomial();
+        let mut other_coeffs = coeffs.clone();
+        other_coeffs[0] += M31::from(1u32);
+        let other_commitment = pcs_commit(&other_coeffs).unwrap();
+        let z = M31::from(1234u32);
+
+        let (y, proof) = pcs_open(&coeffs, z).unwrap();
+        assert!(!pcs_verify(&other_commitment, z, y, &proof).unwrap());
+    }
+}
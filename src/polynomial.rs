@@ -5,10 +5,11 @@
 //! and Reed-Solomon encoding.
 
 use crate::{
-    field::{get_primitive_root_of_unity}, 
+    field::{get_primitive_root_of_unity, powers},
     FriedaError, Result, M31
 };
 use num_traits::identities::{One, Zero};
+use stwo_prover::core::fields::FieldExpOps;
 
 /// Evaluates a polynomial at a specific point
 ///
@@ -34,6 +35,86 @@ pub fn evaluate_polynomial(coeffs: &[M31], point: M31) -> M31 {
     result
 }
 
+/// The `n/2` twiddle factors for a size-`n` FFT, precomputed once so
+/// repeated transforms over the same domain size don't recompute them
+///
+/// `twiddles[i] = omega^i` and `inv_twiddles[i] = omega_inv^i`, where
+/// `omega` is the primitive `domain_size`-th root of unity; stage `s` of
+/// [`fft_in_place`]'s butterfly (half-width `m = 2^s`) indexes into this
+/// same table with a stride of `(n / 2) / m`, since its stage root
+/// `omega^(n / (2m))` is itself one of these `n/2` powers.
+#[derive(Debug, Clone)]
+pub struct TwiddleCache {
+    domain_size: usize,
+    twiddles: Vec<M31>,
+    inv_twiddles: Vec<M31>,
+}
+
+impl TwiddleCache {
+    /// Precomputes the twiddle factors (and their inverses) for a domain of
+    /// the given size
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_size` - The size of the evaluation domain (must be a power of 2)
+    pub fn new(domain_size: usize) -> Result<Self> {
+        if !domain_size.is_power_of_two() {
+            return Err(FriedaError::InvalidInput("Domain size must be a power of 2".to_string()));
+        }
+
+        let omega = get_primitive_root_of_unity(domain_size);
+        let one: M31 = One::one();
+        let omega_inv = one / omega;
+        let half = domain_size / 2;
+
+        Ok(Self {
+            domain_size,
+            twiddles: powers(omega, half),
+            inv_twiddles: powers(omega_inv, half),
+        })
+    }
+}
+
+/// Reorders `a` in place by the bit-reversal permutation of its indices, the
+/// standard first step of an in-place iterative Cooley-Tukey FFT
+fn bit_reverse_permute(a: &mut [M31]) {
+    let n = a.len();
+    if n <= 1 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = (i as u32).reverse_bits() >> (u32::BITS - bits);
+        if (j as usize) > i {
+            a.swap(i, j as usize);
+        }
+    }
+}
+
+/// Runs the butterfly stages of an in-place iterative Cooley-Tukey FFT over
+/// `a`, which must already be in bit-reversed order
+///
+/// Stage `s` has butterfly half-width `m = 2^s` and combines each pair
+/// `a[k+j]`, `a[k+j+m]` with the `j`-th power of that stage's root of unity,
+/// read directly out of `twiddles` with a stride of `twiddles.len() / m`.
+fn fft_in_place(a: &mut [M31], twiddles: &[M31]) {
+    let n = a.len();
+    let mut m = 1;
+    while m < n {
+        let stride = twiddles.len() / m;
+        for k in (0..n).step_by(2 * m) {
+            for j in 0..m {
+                let w = twiddles[j * stride];
+                let u = a[k + j];
+                let v = w * a[k + j + m];
+                a[k + j] = u + v;
+                a[k + j + m] = u - v;
+            }
+        }
+        m *= 2;
+    }
+}
+
 /// Performs Fast Fourier Transform (FFT) on the given coefficients
 ///
 /// # Arguments
@@ -44,63 +125,34 @@ pub fn evaluate_polynomial(coeffs: &[M31], point: M31) -> M31 {
 /// # Returns
 ///
 /// The evaluations of the polynomial at the domain points
-pub fn fft(mut coeffs: Vec<M31>, domain_size: usize) -> Result<Vec<M31>> {
-    if !domain_size.is_power_of_two() {
-        return Err(FriedaError::InvalidInput("Domain size must be a power of 2".to_string()));
-    }
-    
-    // Pad the coefficients with zeros if necessary
-    coeffs.resize(domain_size, M31::default());
-    
-    let omega = get_primitive_root_of_unity(domain_size);
-    
-    // Call the recursive FFT implementation
-    Ok(fft_recursive(&coeffs, domain_size, omega))
+pub fn fft(coeffs: Vec<M31>, domain_size: usize) -> Result<Vec<M31>> {
+    let cache = TwiddleCache::new(domain_size)?;
+    fft_with_twiddles(coeffs, &cache)
 }
 
-/// Recursive implementation of the Fast Fourier Transform
+/// Performs Fast Fourier Transform (FFT) reusing a [`TwiddleCache`] built for
+/// this `domain_size`, avoiding its recomputation across repeated calls
+/// over the same domain
 ///
 /// # Arguments
 ///
-/// * `coeffs` - The coefficients of the polynomial
-/// * `n` - The size of the subproblem (must be a power of 2)
-/// * `omega` - The primitive n-th root of unity
+/// * `coeffs` - The coefficients of the polynomial in ascending order of degree
+/// * `cache` - A twiddle cache built with [`TwiddleCache::new`] for the target domain size
 ///
 /// # Returns
 ///
-/// The result of the FFT
-fn fft_recursive(coeffs: &[M31], n: usize, omega: M31) -> Vec<M31> {
-    if n == 1 {
-        return vec![coeffs[0]];
-    }
-    
-    let n_half = n / 2;
-    
-    // Split coefficients into even and odd indices
-    let mut even = Vec::with_capacity(n_half);
-    let mut odd = Vec::with_capacity(n_half);
-    
-    for i in 0..n_half {
-        even.push(coeffs[2 * i]);
-        odd.push(coeffs[2 * i + 1]);
-    }
-    
-    // Recursively compute FFT on the even and odd parts
-    let omega_squared = omega * omega;
-    let even_fft = fft_recursive(&even, n_half, omega_squared);
-    let odd_fft = fft_recursive(&odd, n_half, omega_squared);
-    
-    // Combine the results
-    let mut result = vec![M31::default(); n];
-    let mut omega_pow: M31 = One::one();
-    
-    for i in 0..n_half {
-        result[i] = even_fft[i] + omega_pow * odd_fft[i];
-        result[i + n_half] = even_fft[i] - omega_pow * odd_fft[i];
-        omega_pow *= omega;
+/// The evaluations of the polynomial at the domain points
+pub fn fft_with_twiddles(mut coeffs: Vec<M31>, cache: &TwiddleCache) -> Result<Vec<M31>> {
+    if coeffs.len() > cache.domain_size {
+        return Err(FriedaError::InvalidInput(
+            "Coefficient count must not exceed the twiddle cache's domain size".to_string(),
+        ));
     }
-    
-    result
+    coeffs.resize(cache.domain_size, M31::default());
+
+    bit_reverse_permute(&mut coeffs);
+    fft_in_place(&mut coeffs, &cache.twiddles);
+    Ok(coeffs)
 }
 
 /// Performs Inverse Fast Fourier Transform (IFFT) on the given evaluations
@@ -113,30 +165,114 @@ fn fft_recursive(coeffs: &[M31], n: usize, omega: M31) -> Vec<M31> {
 /// # Returns
 ///
 /// The coefficients of the polynomial in ascending order of degree
-pub fn ifft(mut evals: Vec<M31>, domain_size: usize) -> Result<Vec<M31>> {
-    if !domain_size.is_power_of_two() {
-        return Err(FriedaError::InvalidInput("Domain size must be a power of 2".to_string()));
+pub fn ifft(evals: Vec<M31>, domain_size: usize) -> Result<Vec<M31>> {
+    let cache = TwiddleCache::new(domain_size)?;
+    ifft_with_twiddles(evals, &cache)
+}
+
+/// Performs Inverse Fast Fourier Transform (IFFT) reusing a [`TwiddleCache`]
+/// built for this `domain_size`, avoiding its recomputation across repeated
+/// calls over the same domain
+///
+/// # Arguments
+///
+/// * `evals` - The evaluations of the polynomial at the domain points
+/// * `cache` - A twiddle cache built with [`TwiddleCache::new`] for the target domain size
+///
+/// # Returns
+///
+/// The coefficients of the polynomial in ascending order of degree
+pub fn ifft_with_twiddles(mut evals: Vec<M31>, cache: &TwiddleCache) -> Result<Vec<M31>> {
+    if evals.len() > cache.domain_size {
+        return Err(FriedaError::InvalidInput(
+            "Evaluation count must not exceed the twiddle cache's domain size".to_string(),
+        ));
     }
-    
-    // Pad the evaluations with zeros if necessary
-    evals.resize(domain_size, M31::default());
-    
-    // Compute the inverse of the primitive root of unity
-    let omega = get_primitive_root_of_unity(domain_size);
-    // We use One for the identity and division for the inverse
+    evals.resize(cache.domain_size, M31::default());
+
+    bit_reverse_permute(&mut evals);
+    fft_in_place(&mut evals, &cache.inv_twiddles);
+
     let one: M31 = One::one();
-    let omega_inv = one / omega;
-    
-    // Call the FFT with the inverse root
-    let mut coeffs = fft_recursive(&evals, domain_size, omega_inv);
-    
-    // Scale by the inverse of domain_size
-    let domain_size_inv = one / M31::from(domain_size as u32);
-    for coeff in &mut coeffs {
+    let domain_size_inv = one / M31::from(cache.domain_size as u32);
+    for coeff in &mut evals {
         *coeff *= domain_size_inv;
     }
-    
-    Ok(coeffs)
+
+    Ok(evals)
+}
+
+/// Evaluates a polynomial over the coset `shift * H` of the size-`domain_size`
+/// subgroup `H`, rather than over `H` itself
+///
+/// Scales coefficient `c_i` by `shift^i` before running the standard [`fft`],
+/// exactly as halo2's extended/coset Lagrange representation does: if
+/// `g(x) = f(shift * x)`, then `g`'s `i`-th coefficient is `f`'s `i`-th
+/// coefficient times `shift^i`, so evaluating `g` over `H` is the same as
+/// evaluating `f` over `shift * H`.
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients of the polynomial in ascending order of degree
+/// * `domain_size` - The size of the evaluation domain (must be a power of 2)
+/// * `shift` - The coset shift; must be nonzero
+///
+/// # Returns
+///
+/// The evaluations of the polynomial over `shift * H`
+pub fn coset_fft(coeffs: &[M31], domain_size: usize, shift: M31) -> Result<Vec<M31>> {
+    let scaled = scale_by_powers(coeffs, shift);
+    fft(scaled, domain_size)
+}
+
+/// Interpolates a polynomial from its evaluations over the coset `shift * H`,
+/// the inverse of [`coset_fft`]
+///
+/// Runs the standard [`ifft`] and then un-scales coefficient `c_i` by
+/// `shift^-i`, undoing [`coset_fft`]'s scaling.
+///
+/// # Arguments
+///
+/// * `evals` - The evaluations of the polynomial over `shift * H`
+/// * `domain_size` - The size of the evaluation domain (must be a power of 2)
+/// * `shift` - The coset shift used to produce `evals`; must be nonzero
+///
+/// # Returns
+///
+/// The coefficients of the polynomial in ascending order of degree
+pub fn coset_ifft(evals: Vec<M31>, domain_size: usize, shift: M31) -> Result<Vec<M31>> {
+    let coeffs = ifft(evals, domain_size)?;
+    let one: M31 = One::one();
+    Ok(scale_by_powers(&coeffs, one / shift))
+}
+
+/// Scales coefficient `c_i` by `base^i`, returning as many coefficients as `coeffs` holds
+fn scale_by_powers(coeffs: &[M31], base: M31) -> Vec<M31> {
+    coeffs
+        .iter()
+        .zip(powers(base, coeffs.len()))
+        .map(|(&c, base_pow)| c * base_pow)
+        .collect()
+}
+
+/// Evaluates the vanishing polynomial `Z_H(x) = x^domain_size - 1` of the
+/// size-`domain_size` subgroup `H` at `point`
+///
+/// `Z_H` is zero on every point of `H` and nowhere else for `point` drawn
+/// outside `H` (such as a coset shift of it), which is exactly what lets a
+/// quotient `q(x) = (f(x) - target(x)) / Z_H(x)` be evaluated safely on a
+/// coset: the divisor this helper computes never vanishes there.
+///
+/// # Arguments
+///
+/// * `point` - The point to evaluate `Z_H` at
+/// * `domain_size` - The size of the subgroup `H` (must be a power of 2)
+///
+/// # Returns
+///
+/// `point^domain_size - 1`
+pub fn vanishing_poly_eval(point: M31, domain_size: usize) -> M31 {
+    point.pow(domain_size as u128) - M31::from(1u32)
 }
 
 /// Performs Reed-Solomon encoding on the given data
@@ -225,6 +361,174 @@ pub fn lagrange_interpolation(evals: &[M31], domain: &[M31]) -> Result<Vec<M31>>
     Ok(result)
 }
 
+/// Computes the coefficients of a polynomial's derivative
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients of the polynomial in ascending order of degree
+///
+/// # Returns
+///
+/// The coefficients of the derivative polynomial in ascending order of degree
+pub fn derivative(coeffs: &[M31]) -> Vec<M31> {
+    if coeffs.len() <= 1 {
+        return vec![M31::default()];
+    }
+
+    (1..coeffs.len())
+        .map(|i| coeffs[i] * M31::from(i as u32))
+        .collect()
+}
+
+/// Multiplies two polynomials via FFT over a domain large enough to hold
+/// the full, un-wrapped product.
+fn poly_mul(a: &[M31], b: &[M31]) -> Vec<M31> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let result_len = a.len() + b.len() - 1;
+    let domain_size = result_len.next_power_of_two();
+    let a_evals = fft(a.to_vec(), domain_size).unwrap();
+    let b_evals = fft(b.to_vec(), domain_size).unwrap();
+    let product_evals: Vec<M31> = a_evals.iter().zip(&b_evals).map(|(&x, &y)| x * y).collect();
+    let mut coeffs = ifft(product_evals, domain_size).unwrap();
+    coeffs.truncate(result_len);
+    coeffs
+}
+
+/// Adds two polynomials given as coefficient vectors in ascending order of
+/// degree, padding the shorter one with zeros.
+fn add_polys(a: &[M31], b: &[M31]) -> Vec<M31> {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| a.get(i).copied().unwrap_or_default() + b.get(i).copied().unwrap_or_default())
+        .collect()
+}
+
+/// Reduces `f` modulo a monic polynomial `g`, returning the remainder.
+fn poly_rem(f: &[M31], g: &[M31]) -> Vec<M31> {
+    let mut remainder = f.to_vec();
+    while remainder.len() >= g.len() {
+        let lead = *remainder.last().unwrap();
+        let shift = remainder.len() - g.len();
+        if lead != M31::default() {
+            for (i, &gi) in g.iter().enumerate() {
+                remainder[shift + i] -= gi * lead;
+            }
+        }
+        remainder.pop();
+    }
+    remainder
+}
+
+/// Builds the subproduct tree for `u`: level 0 holds the degree-1 factors
+/// `(x - u_i)`, and each subsequent level holds the pairwise products of the
+/// previous level's polynomials, halving the node count up to the root,
+/// which holds `M(x) = prod_i (x - u_i)`.
+///
+/// `u.len()` must be a power of two.
+pub fn build_subproduct_tree(u: &[M31]) -> Vec<Vec<Vec<M31>>> {
+    let k = u.len().ilog2() as usize;
+    let one: M31 = One::one();
+    let mut tree: Vec<Vec<Vec<M31>>> = Vec::with_capacity(k + 1);
+    tree.push(u.iter().map(|&ui| vec![-ui, one]).collect());
+
+    for _ in 1..=k {
+        let prev = tree.last().unwrap();
+        let level: Vec<Vec<M31>> = prev
+            .chunks(2)
+            .map(|pair| poly_mul(&pair[0], &pair[1]))
+            .collect();
+        tree.push(level);
+    }
+    tree
+}
+
+/// Evaluates `f` at every point in `points` by recursively reducing it
+/// modulo the subproduct tree, so each half of the points is evaluated
+/// against an exponentially smaller remainder.
+///
+/// `points` must be the same (sub-)array of domain points, at the same
+/// tree `index`, that `tree` was built from.
+pub fn eval_tree(f: &[M31], tree: &[Vec<Vec<M31>>], points: &[M31], index: usize) -> Vec<M31> {
+    if points.len() == 1 {
+        return vec![evaluate_polynomial(f, points[0])];
+    }
+    let k = points.len().ilog2() as usize;
+    let left = poly_rem(f, &tree[k - 1][2 * index]);
+    let right = poly_rem(f, &tree[k - 1][2 * index + 1]);
+    let mid = points.len() / 2;
+    let mut result = eval_tree(&left, tree, &points[..mid], 2 * index);
+    result.extend(eval_tree(&right, tree, &points[mid..], 2 * index + 1));
+    result
+}
+
+/// Combines per-point barycentric weights `c` into the interpolating
+/// polynomial bottom-up, the M31 base-field analogue of
+/// [`crate::reconstruct::linear_combination`].
+pub fn linear_combination(
+    u: &[M31],
+    c: &[M31],
+    tree: &[Vec<Vec<M31>>],
+    level: usize,
+    index: usize,
+) -> Vec<M31> {
+    if c.len() == 1 {
+        return vec![c[0]];
+    }
+    let child_level = level - 1;
+    let mid = u.len() / 2;
+    let r0 = linear_combination(&u[..mid], &c[..mid], tree, child_level, 2 * index);
+    let r1 = linear_combination(&u[mid..], &c[mid..], tree, child_level, 2 * index + 1);
+    let term1 = poly_mul(&tree[child_level][2 * index + 1], &r0);
+    let term2 = poly_mul(&tree[child_level][2 * index], &r1);
+    add_polys(&term1, &term2)
+}
+
+/// Interpolates a polynomial from `u.len()` point/value pairs in
+/// `O(n log^2 n)` work using a subproduct tree, the M31 base-field analogue
+/// of [`crate::reconstruct::fast_interpolation`]'s `Degree2ExtensionField`
+/// version.
+///
+/// `u.len()` must be a power of two; callers with a non-power-of-two sample
+/// count should fall back to [`lagrange_interpolation`].
+///
+/// # Arguments
+///
+/// * `u` - The domain points at which the polynomial was evaluated
+/// * `v` - The evaluations of the polynomial at those points
+///
+/// # Returns
+///
+/// The coefficients of the interpolated polynomial in ascending order of
+/// degree
+pub fn fast_interpolation(u: &[M31], v: &[M31]) -> Result<Vec<M31>> {
+    if u.len() != v.len() {
+        return Err(FriedaError::InvalidInput(
+            "Number of evaluations must match number of domain points".to_string(),
+        ));
+    }
+    if !u.len().is_power_of_two() {
+        return Err(FriedaError::InvalidInput(
+            "fast_interpolation requires a power-of-two sample count".to_string(),
+        ));
+    }
+
+    let tree = build_subproduct_tree(u);
+    let k = u.len().ilog2() as usize;
+    let m = &tree[k][0];
+    let m_deriv = derivative(m);
+    let m_deriv_vals = eval_tree(&m_deriv, &tree, u, 0);
+
+    let c: Vec<M31> = v
+        .iter()
+        .zip(&m_deriv_vals)
+        .map(|(&vi, &m_prime_ui)| vi / m_prime_ui)
+        .collect();
+
+    Ok(linear_combination(u, &c, &tree, k, 0))
+}
+
 /// Checks if a polynomial is low-degree (degree < degree_bound)
 ///
 /// # Arguments
@@ -281,6 +585,75 @@ mod tests {
         assert_eq!(recovered_coeffs[2], coeffs[2]);
     }
 
+    #[test]
+    fn test_fft_with_twiddles_matches_fft() {
+        // Polynomial: 3x^2 + 2x + 1
+        let coeffs = vec![M31::from(1), M31::from(2), M31::from(3)];
+        let domain_size = 8;
+
+        let cache = TwiddleCache::new(domain_size).unwrap();
+        let evals_cached = fft_with_twiddles(coeffs.clone(), &cache).unwrap();
+        let evals = fft(coeffs, domain_size).unwrap();
+        assert_eq!(evals_cached, evals);
+
+        let recovered = ifft_with_twiddles(evals_cached, &cache).unwrap();
+        assert_eq!(recovered, ifft(evals, domain_size).unwrap());
+    }
+
+    #[test]
+    fn test_fft_single_point_domain() {
+        // A size-1 domain has no butterfly stages at all; fft/ifft should
+        // still round-trip a single coefficient.
+        let coeffs = vec![M31::from(42)];
+        let evals = fft(coeffs.clone(), 1).unwrap();
+        assert_eq!(evals, coeffs);
+        assert_eq!(ifft(evals, 1).unwrap(), coeffs);
+    }
+
+    #[test]
+    fn test_coset_fft_and_ifft_round_trip() {
+        // Polynomial: 3x^3 + 2x^2 + x + 5
+        let coeffs = vec![M31::from(5), M31::from(1), M31::from(2), M31::from(3)];
+        let domain_size = 8;
+        let shift = M31::from(7);
+
+        let evals = coset_fft(&coeffs, domain_size, shift).unwrap();
+        let recovered = coset_ifft(evals, domain_size, shift).unwrap();
+        assert_eq!(&recovered[..coeffs.len()], &coeffs[..]);
+    }
+
+    #[test]
+    fn test_coset_fft_matches_direct_evaluation() {
+        let coeffs = vec![M31::from(5), M31::from(1), M31::from(2), M31::from(3)];
+        let domain_size = 4;
+        let shift = M31::from(11);
+
+        let evals = coset_fft(&coeffs, domain_size, shift).unwrap();
+        let omega = get_primitive_root_of_unity(domain_size);
+        let mut point = shift;
+        for &eval in &evals {
+            assert_eq!(eval, evaluate_polynomial(&coeffs, point));
+            point *= omega;
+        }
+    }
+
+    #[test]
+    fn test_vanishing_poly_eval() {
+        let domain_size = 8;
+        let omega = get_primitive_root_of_unity(domain_size);
+
+        // Zero on every point of H itself
+        let mut point: M31 = One::one();
+        for _ in 0..domain_size {
+            assert_eq!(vanishing_poly_eval(point, domain_size), M31::default());
+            point *= omega;
+        }
+
+        // Nonzero on a coset shift of H
+        let shift = M31::from(7);
+        assert_ne!(vanishing_poly_eval(shift, domain_size), M31::default());
+    }
+
     #[test]
     fn test_reed_solomon_encode() {
         // Data: [1, 2, 3]
@@ -299,6 +672,35 @@ mod tests {
         assert_eq!(recovered[2], data[2]);
     }
 
+    #[test]
+    fn test_derivative() {
+        // Polynomial: 3x^2 + 2x + 1, derivative: 6x + 2
+        let coeffs = vec![M31::from(1), M31::from(2), M31::from(3)];
+        assert_eq!(derivative(&coeffs), vec![M31::from(2), M31::from(6)]);
+
+        // A constant's derivative is zero
+        assert_eq!(derivative(&[M31::from(5)]), vec![M31::default()]);
+    }
+
+    #[test]
+    fn test_fast_interpolation_matches_lagrange_interpolation() {
+        // Polynomial: 3x^3 + 2x^2 + x + 5, evaluated on 4 arbitrary points.
+        let coeffs = vec![M31::from(5), M31::from(1), M31::from(2), M31::from(3)];
+        let u = vec![M31::from(1), M31::from(2), M31::from(3), M31::from(4)];
+        let v: Vec<M31> = u.iter().map(|&x| evaluate_polynomial(&coeffs, x)).collect();
+
+        let fast = fast_interpolation(&u, &v).unwrap();
+        let naive = lagrange_interpolation(&v, &u).unwrap();
+        assert_eq!(fast, naive);
+    }
+
+    #[test]
+    fn test_fast_interpolation_rejects_non_power_of_two_sample_count() {
+        let u = vec![M31::from(1), M31::from(2), M31::from(3)];
+        let v = vec![M31::from(1), M31::from(2), M31::from(3)];
+        assert!(fast_interpolation(&u, &v).is_err());
+    }
+
     #[test]
     fn test_is_low_degree() {
         // Polynomial: 3x^2 + 2x + 1 (degree 2)
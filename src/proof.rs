@@ -10,7 +10,7 @@ use stwo_prover::core::{
     fri::{CirclePolyDegreeBound, FriProof, FriProver, FriVerifier},
     pcs::PcsConfig,
     poly::{
-        circle::{CircleDomain, CircleEvaluation, CirclePoly, PolyOps, SecureEvaluation},
+        circle::{CircleDomain, CircleEvaluation, CirclePoly, PolyOps, SecureCirclePoly, SecureEvaluation},
         BitReversedOrder,
     },
     proof_of_work::GrindOps,
@@ -111,6 +111,338 @@ pub fn verify_proof(proof: Proof, seed: Option<u64>) -> bool {
         .is_ok()
 }
 
+/// A FRI proof batching several equally-sized blobs under one Merkle
+/// commitment, mirroring [`Proof`] but carrying the queried evaluations of
+/// every blob for the single shared set of sampled positions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchProof {
+    pub proof: FriProof<Blake2sMerkleHasher>,
+    pub proof_of_work: u64,
+    pub pcs_config: PcsConfig,
+    pub log_size_bound: u32,
+    pub evaluations: Vec<Vec<QM31>>,
+    pub coset_log_size: u32,
+    pub num_blobs: usize,
+}
+
+pub fn batch_generate_proof(blobs: &[&[u8]], seed: Option<u64>, pcs_config: PcsConfig) -> BatchProof {
+    batch_commit_and_generate_proof(blobs, seed, pcs_config).1
+}
+
+/// Commits to `blobs` and proves a single FRI proof over all of them.
+///
+/// Every blob is zero-padded to the length of the largest one so they share
+/// an evaluation domain, and their circle polynomials are passed to
+/// [`FriProver::commit`] as one batch of columns, so a single Merkle
+/// commitment and a single set of query positions cover all of them.
+pub fn batch_commit_and_generate_proof(
+    blobs: &[&[u8]],
+    seed: Option<u64>,
+    pcs_config: PcsConfig,
+) -> (Commitment, BatchProof) {
+    let max_len = blobs.iter().map(|data| data.len()).max().unwrap_or(0);
+    let polynomials: Vec<_> = blobs
+        .iter()
+        .map(|data| {
+            let mut padded = data.to_vec();
+            padded.resize(max_len, 0);
+            utils::polynomial_from_bytes(&padded)
+        })
+        .collect();
+
+    let channel = &mut Blake2sChannel::default();
+    if let Some(seed) = seed {
+        channel.mix_u64(seed);
+    }
+
+    let log_size_bound = polynomials[0].log_size();
+    let coset_log_size = log_size_bound + pcs_config.fri_config.log_blowup_factor - 1;
+    let coset = Coset::half_odds(coset_log_size);
+    let domain = CircleDomain::new(coset);
+    let twiddles = CpuBackend::precompute_twiddles(coset);
+    let secure_evaluations: Vec<SecureEvaluation<CpuBackend, BitReversedOrder>> = polynomials
+        .iter()
+        .map(|polynomial| {
+            let evaluations = polynomial.evaluate_with_twiddles(domain, &twiddles);
+            SecureEvaluation::<CpuBackend, BitReversedOrder>::new(
+                domain,
+                evaluations.into_iter().collect(),
+            )
+        })
+        .collect();
+
+    let fri_prover = FriProver::<CpuBackend, Blake2sMerkleChannel>::commit(
+        channel,
+        pcs_config.fri_config,
+        &secure_evaluations,
+        &twiddles,
+    );
+    let proof_of_work = CpuBackend::grind(channel, pcs_config.pow_bits);
+    channel.mix_u64(proof_of_work);
+    let (proof, queries) = fri_prover.decommit(channel);
+    assert!(queries.keys().len() == 1, "batched blobs must share a log size");
+    let queries = queries.values().next().unwrap();
+
+    let evaluations = secure_evaluations
+        .iter()
+        .map(|secure_evaluation| queries.iter().map(|i| secure_evaluation.at(*i)).collect())
+        .collect();
+    (
+        proof.first_layer.commitment.0,
+        BatchProof {
+            proof,
+            proof_of_work,
+            pcs_config,
+            log_size_bound,
+            evaluations,
+            coset_log_size,
+            num_blobs: blobs.len(),
+        },
+    )
+}
+
+pub fn batch_verify_proof(proof: BatchProof, seed: Option<u64>) -> bool {
+    let channel = &mut Blake2sChannel::default();
+    if let Some(seed) = seed {
+        channel.mix_u64(seed);
+    }
+    let Ok(mut fri_verifier) = FriVerifier::<Blake2sMerkleChannel>::commit(
+        channel,
+        proof.pcs_config.fri_config,
+        proof.proof,
+        vec![CirclePolyDegreeBound::new(proof.log_size_bound); proof.num_blobs],
+    ) else {
+        return false;
+    };
+    channel.mix_u64(proof.proof_of_work);
+    if channel.trailing_zeros() < proof.pcs_config.pow_bits {
+        return false;
+    }
+    let queries = fri_verifier.sample_query_positions(channel);
+    assert!(queries.keys().len() == 1, "batched blobs must share a log size");
+    fri_verifier
+        .decommit(
+            proof
+                .evaluations
+                .into_iter()
+                .map(|blob_evaluations| blob_evaluations.into_iter().collect())
+                .collect(),
+        )
+        .is_ok()
+}
+
+/// Recovers the shared query positions for a batched proof, the same way
+/// [`get_queries_from_proof`] does for a single-blob [`Proof`].
+pub fn get_queries_from_batch_proof(proof: BatchProof, seed: Option<u64>) -> (u32, Vec<usize>) {
+    let channel = &mut Blake2sChannel::default();
+    if let Some(seed) = seed {
+        channel.mix_u64(seed);
+    }
+    let Ok(mut fri_verifier) = FriVerifier::<Blake2sMerkleChannel>::commit(
+        channel,
+        proof.pcs_config.fri_config,
+        proof.proof,
+        vec![CirclePolyDegreeBound::new(proof.log_size_bound); proof.num_blobs],
+    ) else {
+        panic!("Failed to commit");
+    };
+    channel.mix_u64(proof.proof_of_work);
+    if channel.trailing_zeros() < proof.pcs_config.pow_bits {
+        panic!("Proof of work is invalid");
+    }
+    let queries = fri_verifier.sample_query_positions(channel);
+    queries.into_iter().next().unwrap()
+}
+
+/// A [`Proof`] generated in hiding mode: the Merkle-committed evaluations
+/// are of `data_poly + r` for a random masking polynomial `r` derived from
+/// a `hiding_seed` that is deliberately *not* part of this struct.
+///
+/// A single light client only ever receives a `HidingProof`, so its sampled
+/// evaluations alone never reveal the underlying data; recovering it
+/// requires `hiding_seed` itself, which [`unmask_hiding_evaluations`] takes
+/// as a separate argument from whoever generated the proof (an aggregator,
+/// or a quorum-reconstruction path), not from the proof.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HidingProof {
+    pub proof: FriProof<Blake2sMerkleHasher>,
+    pub proof_of_work: u64,
+    pub pcs_config: PcsConfig,
+    pub log_size_bound: u32,
+    pub evaluations: Vec<QM31>,
+    pub coset_log_size: u32,
+}
+
+/// Draws a random `SecureCirclePoly` of the given `log_size` from a channel
+/// seeded only with `hiding_seed`, so anyone holding that seed (the prover,
+/// and later whoever calls [`unmask_hiding_evaluations`] with it) can
+/// reproduce the exact same masking polynomial.
+fn masking_polynomial(log_size: u32, hiding_seed: u64) -> SecureCirclePoly<CpuBackend> {
+    let channel = &mut Blake2sChannel::default();
+    channel.mix_u64(hiding_seed);
+    let coefficients: Vec<M31> = channel
+        .draw_felts(1 << log_size)
+        .iter()
+        .flat_map(|felt| felt.to_m31_array())
+        .collect();
+    utils::polynomial_from_felts(coefficients)
+}
+
+fn add_secure_polys(
+    a: &SecureCirclePoly<CpuBackend>,
+    b: &SecureCirclePoly<CpuBackend>,
+) -> SecureCirclePoly<CpuBackend> {
+    let columns: [CirclePoly<CpuBackend>; 4] = std::array::from_fn(|i| {
+        let coeffs = a.0[i]
+            .coeffs
+            .iter()
+            .zip(b.0[i].coeffs.iter())
+            .map(|(x, y)| *x + *y)
+            .collect();
+        CirclePoly::<CpuBackend>::new(coeffs)
+    });
+    SecureCirclePoly(columns)
+}
+
+pub fn generate_hiding_proof(
+    data: &[u8],
+    seed: Option<u64>,
+    hiding_seed: u64,
+    pcs_config: PcsConfig,
+) -> HidingProof {
+    commit_and_generate_hiding_proof(data, seed, hiding_seed, pcs_config).1
+}
+
+/// Commits to `data + r` for a random masking polynomial `r` derived from
+/// `hiding_seed` and proves a FRI proof over the masked polynomial, the
+/// hiding-mode counterpart of [`commit_and_generate_proof`].
+pub fn commit_and_generate_hiding_proof(
+    data: &[u8],
+    seed: Option<u64>,
+    hiding_seed: u64,
+    pcs_config: PcsConfig,
+) -> (Commitment, HidingProof) {
+    let data_poly = utils::polynomial_from_bytes(data);
+    let mask_poly = masking_polynomial(data_poly.log_size(), hiding_seed);
+    let masked_poly = add_secure_polys(&data_poly, &mask_poly);
+
+    let channel = &mut Blake2sChannel::default();
+    if let Some(seed) = seed {
+        channel.mix_u64(seed);
+    }
+
+    let coset_log_size = masked_poly.log_size() + pcs_config.fri_config.log_blowup_factor - 1;
+    let coset = Coset::half_odds(coset_log_size);
+    let domain = CircleDomain::new(coset);
+    let twiddles = CpuBackend::precompute_twiddles(coset);
+    let evaluations: SecureEvaluation<CpuBackend, BitReversedOrder> =
+        masked_poly.evaluate_with_twiddles(domain, &twiddles);
+    let secure_evaluations = [SecureEvaluation::<CpuBackend, BitReversedOrder>::new(
+        domain,
+        evaluations.into_iter().collect(),
+    ); 1];
+
+    let fri_prover = FriProver::<CpuBackend, Blake2sMerkleChannel>::commit(
+        channel,
+        pcs_config.fri_config,
+        &secure_evaluations,
+        &twiddles,
+    );
+    let proof_of_work = CpuBackend::grind(channel, pcs_config.pow_bits);
+    channel.mix_u64(proof_of_work);
+    let (proof, queries) = fri_prover.decommit(channel);
+    assert!(queries.keys().len() == 1);
+    let queries = queries.values().next().unwrap();
+
+    let evaluations = queries
+        .iter()
+        .map(|i| secure_evaluations[0].at(*i))
+        .collect();
+    (
+        proof.first_layer.commitment.0,
+        HidingProof {
+            proof,
+            proof_of_work,
+            pcs_config,
+            log_size_bound: masked_poly.log_size(),
+            evaluations,
+            coset_log_size,
+        },
+    )
+}
+
+pub fn verify_hiding_proof(proof: HidingProof, seed: Option<u64>) -> bool {
+    let channel = &mut Blake2sChannel::default();
+    if let Some(seed) = seed {
+        channel.mix_u64(seed);
+    }
+    let Ok(mut fri_verifier) = FriVerifier::<Blake2sMerkleChannel>::commit(
+        channel,
+        proof.pcs_config.fri_config,
+        proof.proof,
+        vec![CirclePolyDegreeBound::new(proof.log_size_bound)],
+    ) else {
+        return false;
+    };
+    channel.mix_u64(proof.proof_of_work);
+    if channel.trailing_zeros() < proof.pcs_config.pow_bits {
+        return false;
+    }
+    let queries = fri_verifier.sample_query_positions(channel);
+    assert!(queries.keys().len() == 1);
+    fri_verifier
+        .decommit(vec![proof.evaluations.into_iter().collect()])
+        .is_ok()
+}
+
+pub fn get_queries_from_hiding_proof(proof: HidingProof, seed: Option<u64>) -> (u32, Vec<usize>) {
+    let channel = &mut Blake2sChannel::default();
+    if let Some(seed) = seed {
+        channel.mix_u64(seed);
+    }
+    let Ok(mut fri_verifier) = FriVerifier::<Blake2sMerkleChannel>::commit(
+        channel,
+        proof.pcs_config.fri_config,
+        proof.proof,
+        vec![CirclePolyDegreeBound::new(proof.log_size_bound)],
+    ) else {
+        panic!("Failed to commit");
+    };
+    channel.mix_u64(proof.proof_of_work);
+    if channel.trailing_zeros() < proof.pcs_config.pow_bits {
+        panic!("Proof of work is invalid");
+    }
+    let queries = fri_verifier.sample_query_positions(channel);
+    queries.into_iter().next().unwrap()
+}
+
+/// Recovers the evaluations of the original, unmasked data polynomial at a
+/// [`HidingProof`]'s query points by re-deriving the masking polynomial `r`
+/// from `hiding_seed` and subtracting its evaluations back out.
+///
+/// `hiding_seed` is not part of `HidingProof` itself, so a lone recipient of
+/// the proof cannot call this on their own; it must be supplied separately
+/// by whoever generated the proof (an aggregator, or a quorum-reconstruction
+/// path), matching the same seed passed to [`generate_hiding_proof`].
+pub fn unmask_hiding_evaluations(
+    proof: HidingProof,
+    hiding_seed: u64,
+    seed: Option<u64>,
+) -> Vec<QM31> {
+    let mask_poly = masking_polynomial(proof.log_size_bound, hiding_seed);
+    let coset = Coset::half_odds(proof.coset_log_size);
+    let domain = CircleDomain::new(coset);
+    let twiddles = CpuBackend::precompute_twiddles(coset);
+    let mask_evaluations = mask_poly.evaluate_with_twiddles(domain, &twiddles);
+
+    let (_, positions) = get_queries_from_hiding_proof(proof.clone(), seed);
+    positions
+        .iter()
+        .zip(proof.evaluations.iter())
+        .map(|(&i, &masked)| masked - mask_evaluations.at(i))
+        .collect()
+}
+
 pub fn get_queries_from_proof(proof: Proof, seed: Option<u64>) -> (u32, Vec<usize>) {
     let channel = &mut Blake2sChannel::default();
     if let Some(seed) = seed {
@@ -212,6 +544,90 @@ mod tests {
         assert!(!verify_proof(proof, None));
     }
 
+    #[test]
+    fn test_batch_generate_and_verify_proof() {
+        let blob_a = include_bytes!("../blob");
+        let blob_b = b"a second, shorter blob batched alongside the first one";
+        let (commitment, proof) = batch_commit_and_generate_proof(&[blob_a, blob_b], None, PCS_CONFIG);
+        assert_eq!(proof.num_blobs, 2);
+        assert_eq!(proof.evaluations.len(), 2);
+        assert_eq!(
+            commitment,
+            crate::commit::batch_commit(&[blob_a, blob_b], PCS_CONFIG.fri_config.log_blowup_factor)
+        );
+        assert!(batch_verify_proof(proof, None));
+    }
+
+    #[test]
+    fn test_batch_verify_proof_with_tampered_evaluation() {
+        let blob_a = include_bytes!("../blob");
+        let blob_b = b"a second, shorter blob batched alongside the first one";
+        let mut proof = batch_generate_proof(&[blob_a, blob_b], None, PCS_CONFIG);
+        proof.evaluations[0][0] += M31::from_u32_unchecked(1);
+        assert!(!batch_verify_proof(proof, None));
+    }
+
+    #[test]
+    fn test_generate_hiding_proof() {
+        let data = include_bytes!("../blob");
+        let proof = generate_hiding_proof(data, None, 1, PCS_CONFIG);
+        assert_ne!(proof.proof.inner_layers.len(), 0);
+    }
+
+    #[test]
+    fn test_commit_and_generate_hiding_proof() {
+        let data = include_bytes!("../blob");
+        let (commitment, proof) = commit_and_generate_hiding_proof(data, None, 1, PCS_CONFIG);
+        assert_eq!(proof.proof.first_layer.commitment.0, commitment);
+    }
+
+    #[test]
+    fn test_verify_hiding_proof() {
+        let data = include_bytes!("../blob");
+        let proof = generate_hiding_proof(data, None, 1, PCS_CONFIG);
+        assert!(verify_hiding_proof(proof, None));
+    }
+
+    #[test]
+    fn test_verify_hiding_proof_with_tampered_evaluation() {
+        let data = include_bytes!("../blob");
+        let mut proof = generate_hiding_proof(data, None, 1, PCS_CONFIG);
+        proof.evaluations[0] += M31::from_u32_unchecked(1);
+        assert!(!verify_hiding_proof(proof, None));
+    }
+
+    #[test]
+    fn test_hiding_proof_evaluations_differ_from_plain_data_evaluations() {
+        let data = include_bytes!("../blob");
+        let proof = generate_hiding_proof(data, None, 7, PCS_CONFIG);
+
+        let data_poly = utils::polynomial_from_bytes(data);
+        let coset = Coset::half_odds(proof.coset_log_size);
+        let domain = CircleDomain::new(coset);
+        let twiddles = CpuBackend::precompute_twiddles(coset);
+        let data_evaluations = data_poly.evaluate_with_twiddles(domain, &twiddles);
+        let (_, positions) = get_queries_from_hiding_proof(proof.clone(), None);
+        let plain: Vec<QM31> = positions.iter().map(|&i| data_evaluations.at(i)).collect();
+
+        assert_ne!(proof.evaluations, plain);
+    }
+
+    #[test]
+    fn test_unmask_hiding_evaluations_recovers_data_poly_evaluations() {
+        let data = include_bytes!("../blob");
+        let proof = generate_hiding_proof(data, None, 42, PCS_CONFIG);
+
+        let data_poly = utils::polynomial_from_bytes(data);
+        let coset = Coset::half_odds(proof.coset_log_size);
+        let domain = CircleDomain::new(coset);
+        let twiddles = CpuBackend::precompute_twiddles(coset);
+        let data_evaluations = data_poly.evaluate_with_twiddles(domain, &twiddles);
+        let (_, positions) = get_queries_from_hiding_proof(proof.clone(), None);
+        let expected: Vec<QM31> = positions.iter().map(|&i| data_evaluations.at(i)).collect();
+
+        assert_eq!(unmask_hiding_evaluations(proof, 42, None), expected);
+    }
+
     #[test]
     fn test_verify_proof_with_seed() {
         let data = include_bytes!("../blob");
@@ -7,15 +7,19 @@ use lambdaworks_math::polynomial::{pad_with_zero_coefficients_to_length, Polynom
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
 };
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
 use stwo_prover::core::backend::CpuBackend;
 use stwo_prover::core::channel::{Blake2sChannel, Channel};
-use stwo_prover::core::circle::CirclePoint as StwoCirclePoint;
+use stwo_prover::core::circle::{CirclePoint as StwoCirclePoint, Coset as StwoCoset};
 use stwo_prover::core::fields::{m31::M31, qm31::QM31};
 use stwo_prover::core::fri::{CirclePolyDegreeBound, FriVerifier};
-use stwo_prover::core::poly::circle::{CirclePoly, SecureCirclePoly};
+use stwo_prover::core::poly::circle::{CircleDomain, CirclePoly, SecureCirclePoly};
+use stwo_prover::core::utils::bit_reverse_index;
 use stwo_prover::core::vcs::blake2_merkle::Blake2sMerkleChannel;
 
-use crate::proof::Proof;
+use crate::proof::{get_queries_from_batch_proof, BatchProof, Proof};
+use crate::utils;
 
 pub fn fast_circle_interpolation(
     xs: &[StwoCirclePoint<M31>],
@@ -289,6 +293,134 @@ pub fn get_queries_from_proof(proof: Proof, seed: Option<u64>) -> (u32, Vec<usiz
     let queries = fri_verifier.sample_query_positions(channel);
     queries.into_iter().next().unwrap()
 }
+
+/// Reconstructs every blob batched into `proofs` from a single shared query
+/// schedule, the batched counterpart of [`crate::api::reconstruct`].
+///
+/// Each `(proof, seed)` pair contributes the evaluations it opened at its
+/// own query positions; once enough distinct positions have been gathered
+/// across all proofs, every blob is interpolated independently via
+/// [`fast_circle_interpolation`].
+pub fn batch_reconstruct(proofs: Vec<(BatchProof, Option<u64>)>) -> Vec<Vec<u8>> {
+    let num_blobs = proofs[0].0.num_blobs;
+    let coset = StwoCoset::half_odds(proofs[0].0.coset_log_size);
+    let domain = CircleDomain::new(coset);
+    let poly_log_size = proofs[0].0.log_size_bound;
+
+    let mut pos_set = HashSet::new();
+    let mut xs = Vec::with_capacity(1 << domain.log_size());
+    let mut evals_vecs = vec![Vec::with_capacity(1 << domain.log_size()); num_blobs];
+    for (proof, seed) in proofs {
+        let (_, positions) = get_queries_from_batch_proof(proof.clone(), seed);
+        for (i, p) in positions.iter().enumerate() {
+            let point = domain.at(bit_reverse_index(*p, domain.log_size()));
+            if pos_set.insert(point) {
+                xs.push(point);
+                for (blob_index, evals) in evals_vecs.iter_mut().enumerate() {
+                    evals.push(proof.evaluations[blob_index][i]);
+                }
+            }
+        }
+    }
+
+    let evals_nb = (1 << poly_log_size) + 1;
+    evals_vecs
+        .iter()
+        .map(|evals| {
+            let interpolated_poly = fast_circle_interpolation(&xs[..evals_nb], &evals[..evals_nb]);
+            let interpolated = interpolated_poly.0[0]
+                .coeffs
+                .iter()
+                .zip(&interpolated_poly.0[1].coeffs)
+                .zip(&interpolated_poly.0[2].coeffs)
+                .zip(&interpolated_poly.0[3].coeffs)
+                .flat_map(|(((a, b), c), d)| [a, b, c, d])
+                .collect::<Vec<&M31>>();
+            utils::decode_framed(&interpolated)
+        })
+        .collect()
+}
+
+/// Errors returned by [`checked_reconstruct`] when a set of proofs cannot be
+/// safely decoded into the original data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconstructError {
+    /// Fewer distinct `(point, eval)` pairs were collected across every
+    /// proof than the degree bound requires to interpolate uniquely
+    Insufficient { have: usize, need: usize },
+    /// Two proofs opened different evaluations at the same sampled
+    /// position, which can only happen if at least one sampler is
+    /// malicious or buggy
+    Conflict { position: usize },
+}
+
+/// Reconstructs the original data from a set of `(proof, seed)` pairs,
+/// tolerating overlapping or partial sample sets the way standard
+/// Reed-Solomon erasure decoding does: any `(1 << poly_log_size) + 1`
+/// distinct evaluation points suffice, regardless of which proofs
+/// contributed them.
+///
+/// Unlike [`batch_reconstruct`]'s unchecked slicing, this rejects a
+/// quorum that is too small with [`ReconstructError::Insufficient`] instead
+/// of panicking on an out-of-bounds slice, and rejects two proofs that
+/// disagree about the evaluation at the same position with
+/// [`ReconstructError::Conflict`] instead of silently keeping whichever one
+/// happened to be inserted first.
+///
+/// # Arguments
+///
+/// * `proofs` - The proofs to reconstruct from, paired with the seed each
+///   one was generated with (`None` if no seed was used)
+///
+/// # Returns
+///
+/// The reconstructed data, truncated to the exact byte length the provider
+/// originally committed via [`crate::utils::encode_framed`]'s length header
+pub fn checked_reconstruct(proofs: Vec<(Proof, Option<u64>)>) -> Result<Vec<u8>, ReconstructError> {
+    let coset = StwoCoset::half_odds(proofs[0].0.coset_log_size);
+    let domain = CircleDomain::new(coset);
+    let poly_log_size = proofs[0].0.log_size_bound;
+    let need = (1usize << poly_log_size) + 1;
+
+    let mut evals_by_point: HashMap<StwoCirclePoint<M31>, QM31> = HashMap::new();
+    for (proof, seed) in proofs {
+        let (_, positions) = get_queries_from_proof(proof.clone(), seed);
+        for (i, &position) in positions.iter().enumerate() {
+            let point = domain.at(bit_reverse_index(position, domain.log_size()));
+            let eval = proof.evaluations[i];
+            match evals_by_point.entry(point) {
+                Entry::Occupied(existing) => {
+                    if *existing.get() != eval {
+                        return Err(ReconstructError::Conflict { position });
+                    }
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(eval);
+                }
+            }
+        }
+    }
+
+    if evals_by_point.len() < need {
+        return Err(ReconstructError::Insufficient {
+            have: evals_by_point.len(),
+            need,
+        });
+    }
+
+    let (xs, evals): (Vec<_>, Vec<_>) = evals_by_point.into_iter().take(need).unzip();
+    let interpolated_poly = fast_circle_interpolation(&xs, &evals);
+    let interpolated = interpolated_poly.0[0]
+        .coeffs
+        .iter()
+        .zip(&interpolated_poly.0[1].coeffs)
+        .zip(&interpolated_poly.0[2].coeffs)
+        .zip(&interpolated_poly.0[3].coeffs)
+        .flat_map(|(((a, b), c), d)| [a, b, c, d])
+        .collect::<Vec<&M31>>();
+    Ok(utils::decode_framed(&interpolated))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -353,7 +485,7 @@ mod tests {
             .zip(interpolated.0[3].coeffs.iter())
             .flat_map(|(((a, b), c), d)| [a, b, c, d])
             .collect::<Vec<_>>();
-        let interpolated_bytes = utils::felts_to_bytes_le(&interpolated);
+        let interpolated_bytes = utils::decode_framed(&interpolated);
 
         data.iter()
             .zip(interpolated_bytes.iter())
@@ -365,6 +497,39 @@ mod tests {
             });
         println!("interpolated_bytes.len(): {:?}", interpolated_bytes.len());
         println!("data.len(): {:?}", data.len());
-        assert_eq!(data, interpolated_bytes[..data.len()]);
+        assert_eq!(data, interpolated_bytes);
+    }
+
+    #[test]
+    fn test_checked_reconstruct_recovers_data_from_overlapping_proofs() {
+        let data = include_bytes!("../blob").to_vec();
+        let poly = utils::polynomial_from_bytes(&data);
+
+        // More proofs than strictly necessary, each with overlapping sample
+        // points, mirroring light clients that independently over-sample.
+        let samples_nb = (1 << (poly.log_size() + 1)) / PCS_CONFIG.fri_config.n_queries;
+        let proofs = (0..=samples_nb + 2)
+            .map(|i| {
+                let seed = Some(i as u64);
+                (generate_proof(&data, seed, PCS_CONFIG), seed)
+            })
+            .collect::<Vec<_>>();
+
+        let reconstructed = checked_reconstruct(proofs).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_checked_reconstruct_rejects_insufficient_quorum() {
+        let data = include_bytes!("../blob").to_vec();
+
+        // A single proof's own query positions are far fewer than the
+        // degree bound requires.
+        let proofs = vec![(generate_proof(&data, Some(0), PCS_CONFIG), Some(0))];
+
+        assert!(matches!(
+            checked_reconstruct(proofs),
+            Err(ReconstructError::Insufficient { .. })
+        ));
     }
 }
@@ -5,11 +5,18 @@
 //! data availability without downloading the entire dataset.
 
 use crate::{
-    field::get_primitive_root_of_unity, polynomial, Commitment, FriedaError, Result, SampleResult,
-    M31,
+    da::FriProverContext,
+    field::get_primitive_root_of_unity,
+    polynomial,
+    utils::{self, MerkleTree},
+    Commitment, FriedaError, Result, SampleResult, M31,
 };
 use num_traits::identities::{One, Zero};
-use sha2::{Digest, Sha256};
+use stwo_prover::core::{
+    backend::CpuBackend,
+    channel::{Blake2sChannel, Channel},
+    proof_of_work::GrindOps,
+};
 
 /// The statistical security parameter
 const STATISTICAL_SECURITY: usize = 40;
@@ -19,11 +26,25 @@ const STATISTICAL_SECURITY: usize = 40;
 /// # Arguments
 ///
 /// * `commitment` - The commitment to sample from
+/// * `context` - The prover context returned by `da::commit_with_context`,
+///   needed to open the sampled indices against the committed codeword
+/// * `nonce` - A client-supplied nonce, so independent clients sampling the
+///   same commitment draw different, unpredictable positions
+/// * `pow_bits` - The number of trailing zero bits of grinding required on
+///   the index-generation channel before the drawn indices are accepted
 ///
 /// # Returns
 ///
-/// A sample result containing the sampled values and indices
-pub fn sample(commitment: &Commitment) -> Result<SampleResult> {
+/// A sample result containing the sampled values, their indices, a Merkle
+/// authentication path per sample against `commitment.root`, and the
+/// `(nonce, proof_of_work)` pair a verifier needs to re-derive the same
+/// indices
+pub fn sample(
+    commitment: &Commitment,
+    context: &FriProverContext,
+    nonce: u64,
+    pow_bits: u32,
+) -> Result<SampleResult> {
     // Calculate the number of samples needed
     let samples_needed = calculate_samples_needed(
         commitment.metadata.domain_size,
@@ -31,17 +52,35 @@ pub fn sample(commitment: &Commitment) -> Result<SampleResult> {
         STATISTICAL_SECURITY,
     );
 
-    // Generate random sample indices
-    let indices = generate_sample_indices(commitment.metadata.domain_size, samples_needed)?;
+    // Generate sample indices bound to this commitment, the client's nonce,
+    // and a proof-of-work grind, so a data provider cannot predict (and
+    // therefore selectively withhold) the positions that will be queried.
+    let (indices, proof_of_work) = generate_sample_indices(
+        &commitment.root,
+        commitment.metadata.domain_size,
+        samples_needed,
+        nonce,
+        pow_bits,
+    );
 
-    // This would normally involve querying a data provider for the values and proofs at these indices
-    // For demonstration purposes, we'll return a placeholder result
+    // Open each sampled index against the codeword and Merkle tree retained
+    // in the prover context, so the light client gets an authenticated value
+    // rather than a bare claim.
+    let mut values = Vec::with_capacity(indices.len());
+    let mut proofs = Vec::with_capacity(indices.len());
+    for &index in &indices {
+        values.push(context.encoded[index]);
+        proofs.push(context.tree.get_auth_path(index)?);
+    }
 
-    return Ok(SampleResult {
-        success: false,
-        values: Vec::new(),
+    Ok(SampleResult {
+        success: true,
+        values,
         indices,
-    });
+        proofs,
+        nonce,
+        proof_of_work,
+    })
 }
 
 /// Calculates the number of samples needed for a given statistical security
@@ -84,41 +123,96 @@ fn calculate_samples_needed(domain_size: usize, degree: usize, security_param: u
     s.ceil() as usize
 }
 
-/// Generates random sample indices
+/// Mixes a 32-byte Merkle root into a Blake2s Fiat-Shamir channel, so
+/// downstream challenges are bound to this specific commitment.
+fn mix_commitment_root(channel: &mut Blake2sChannel, commitment_root: &[u8; 32]) {
+    let words: Vec<u32> = commitment_root
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    channel.mix_u32s(&words);
+}
+
+/// Draws `num_samples` indices in `[0, domain_size)` from a channel's
+/// output stream.
+fn draw_indices(channel: &mut Blake2sChannel, domain_size: usize, num_samples: usize) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(num_samples);
+    while indices.len() < num_samples {
+        for chunk in channel.draw_random_bytes().chunks_exact(8) {
+            if indices.len() == num_samples {
+                break;
+            }
+            indices.push(u64::from_le_bytes(chunk.try_into().unwrap()) as usize % domain_size);
+        }
+    }
+    indices
+}
+
+/// Generates sample indices bound to a specific commitment via a
+/// Blake2s-based Fiat-Shamir channel, and grinds a proof-of-work nonce so
+/// the final channel state has `pow_bits` trailing zeros before indices are
+/// drawn from it.
+///
+/// This replaces sampling from a fixed, public seed (under which every
+/// client queries the same positions and a provider can simply make sure
+/// those positions are available) with indices that are unpredictable until
+/// the commitment is known and costly to re-roll, deterring an adaptive
+/// provider from withholding exactly the unqueried region.
 ///
 /// # Arguments
 ///
+/// * `commitment_root` - The Merkle root being sampled
 /// * `domain_size` - The size of the evaluation domain
 /// * `num_samples` - The number of samples to generate
+/// * `nonce` - A client-supplied nonce
+/// * `pow_bits` - The number of trailing zero bits of grinding required
 ///
 /// # Returns
 ///
-/// A vector of random sample indices
-fn generate_sample_indices(domain_size: usize, num_samples: usize) -> Result<Vec<usize>> {
-    // In a real implementation, this would use a true random source or a cryptographic RNG
-    // Here, we'll use a deterministic approach for simplicity
-
-    let mut indices = Vec::new();
-
-    // Generate a seed for the random sample generation
-    let mut hasher = Sha256::new();
-    hasher.update(b"SAMPLE_INDICES");
-    let seed = hasher.finalize();
-
-    // Use the seed to generate random indices
-    for i in 0..num_samples {
-        let mut hasher = Sha256::new();
-        hasher.update(seed);
-        hasher.update(i.to_le_bytes());
-        let digest = hasher.finalize();
-
-        // Convert the digest to an index
-        let index = u64::from_le_bytes(digest[0..8].try_into().unwrap()) as usize % domain_size;
+/// A tuple of the drawn sample indices and the proof-of-work nonce that
+/// unlocked them
+fn generate_sample_indices(
+    commitment_root: &[u8; 32],
+    domain_size: usize,
+    num_samples: usize,
+    nonce: u64,
+    pow_bits: u32,
+) -> (Vec<usize>, u64) {
+    let channel = &mut Blake2sChannel::default();
+    mix_commitment_root(channel, commitment_root);
+    channel.mix_u64(nonce);
+
+    let proof_of_work = CpuBackend::grind(channel, pow_bits);
+    channel.mix_u64(proof_of_work);
+
+    let indices = draw_indices(channel, domain_size, num_samples);
+    (indices, proof_of_work)
+}
 
-        indices.push(index);
+/// Re-derives the sample indices a `(nonce, proof_of_work)` pair commits to,
+/// the verifier-side counterpart of `generate_sample_indices`.
+///
+/// Returns `None` if `proof_of_work` does not grind the channel to
+/// `pow_bits` trailing zeros, since in that case the indices were never
+/// honestly unlocked.
+fn verify_sample_indices(
+    commitment_root: &[u8; 32],
+    domain_size: usize,
+    num_samples: usize,
+    nonce: u64,
+    proof_of_work: u64,
+    pow_bits: u32,
+) -> Option<Vec<usize>> {
+    let channel = &mut Blake2sChannel::default();
+    mix_commitment_root(channel, commitment_root);
+    channel.mix_u64(nonce);
+    channel.mix_u64(proof_of_work);
+
+    if channel.trailing_zeros() < pow_bits {
+        return None;
     }
 
-    Ok(indices)
+    Some(draw_indices(channel, domain_size, num_samples))
 }
 
 /// Verifies a sampling result
@@ -127,14 +221,20 @@ fn generate_sample_indices(domain_size: usize, num_samples: usize) -> Result<Vec
 ///
 /// * `commitment` - The commitment to verify against
 /// * `result` - The sample result to verify
+/// * `pow_bits` - The number of trailing zero bits of grinding that was
+///   required of `result.proof_of_work` when the sample was generated
 ///
 /// # Returns
 ///
-/// `true` if the sample result is valid, `false` otherwise
-pub fn verify_sampling(commitment: &Commitment, result: &SampleResult) -> Result<bool> {
-    if result.indices.len() != result.values.len() {
+/// `true` if `result`'s indices are the ones its `(nonce, proof_of_work)`
+/// pair commits to and every sample's Merkle authentication path opens to
+/// `commitment.root`, `false` if either check fails; malformed input, such
+/// as mismatched `indices`/`values`/`proofs` lengths or an authentication
+/// path whose length is not the domain's Merkle depth, is an `Err`
+pub fn verify_sampling(commitment: &Commitment, result: &SampleResult, pow_bits: u32) -> Result<bool> {
+    if result.indices.len() != result.values.len() || result.indices.len() != result.proofs.len() {
         return Err(FriedaError::InvalidInput(
-            "Indices and values must have the same length".to_string(),
+            "Indices, values and proofs must all have the same length".to_string(),
         ));
     }
 
@@ -153,8 +253,43 @@ pub fn verify_sampling(commitment: &Commitment, result: &SampleResult) -> Result
         )));
     }
 
-    // In a real implementation, we would verify Merkle paths for each sample
-    // For demonstration purposes, we'll always return true
+    // Confirm the claimed indices are really the ones the commitment-bound,
+    // grinded channel would have produced, rather than a cherry-picked set.
+    let expected_indices = verify_sample_indices(
+        &commitment.root,
+        commitment.metadata.domain_size,
+        result.indices.len(),
+        result.nonce,
+        result.proof_of_work,
+        pow_bits,
+    );
+    if expected_indices.as_deref() != Some(result.indices.as_slice()) {
+        return Ok(false);
+    }
+
+    // An authentication path's length is fixed by the commitment's domain
+    // size, so a path of the wrong length can never open to `commitment.root`
+    // and is rejected as malformed rather than merely "didn't match".
+    let expected_depth = commitment.metadata.domain_size.ilog2() as usize;
+
+    for ((&index, &value), auth_path) in result
+        .indices
+        .iter()
+        .zip(&result.values)
+        .zip(&result.proofs)
+    {
+        if auth_path.len() != expected_depth {
+            return Err(FriedaError::InvalidMerklePath(format!(
+                "Expected an authentication path of length {expected_depth}, got {}",
+                auth_path.len()
+            )));
+        }
+
+        let leaf_hash = utils::hash(&utils::m31_to_bytes(value));
+        if !MerkleTree::verify_inclusion(&leaf_hash, index, auth_path, &commitment.root) {
+            return Ok(false);
+        }
+    }
 
     Ok(true)
 }
@@ -177,6 +312,7 @@ pub fn aggregate_sampling(results: &[SampleResult]) -> Result<SampleResult> {
 
     let mut aggregated_indices = Vec::new();
     let mut aggregated_values = Vec::new();
+    let mut aggregated_proofs = Vec::new();
 
     // Collect unique samples from all results
     for result in results {
@@ -184,14 +320,22 @@ pub fn aggregate_sampling(results: &[SampleResult]) -> Result<SampleResult> {
             if !aggregated_indices.contains(&index) {
                 aggregated_indices.push(index);
                 aggregated_values.push(result.values[i]);
+                aggregated_proofs.push(result.proofs[i].clone());
             }
         }
     }
 
+    // An aggregated result merges samples drawn under several different
+    // (nonce, proof_of_work) pairs, so it has no single one of its own to
+    // report; `verify_sampling` is meant to be called per-source-result
+    // before aggregating, not against the aggregate itself.
     Ok(SampleResult {
         success: true,
         values: aggregated_values,
         indices: aggregated_indices,
+        proofs: aggregated_proofs,
+        nonce: 0,
+        proof_of_work: 0,
     })
 }
 
@@ -232,8 +376,14 @@ pub fn reconstruct_polynomial(result: &SampleResult, domain_size: usize) -> Resu
         sample_values.push(result.values[i]);
     }
 
-    // Interpolate the polynomial
-    polynomial::lagrange_interpolation(&sample_values, &sample_points)
+    // Interpolate the polynomial. `fast_interpolation` needs a power-of-two
+    // sample count to build its subproduct tree; fall back to the naive
+    // O(n^2) Lagrange interpolation otherwise.
+    if sample_points.len().is_power_of_two() {
+        polynomial::fast_interpolation(&sample_points, &sample_values)
+    } else {
+        polynomial::lagrange_interpolation(&sample_values, &sample_points)
+    }
 }
 
 #[cfg(test)]
@@ -262,8 +412,9 @@ mod tests {
     fn test_generate_sample_indices() {
         let domain_size = 256;
         let num_samples = 40;
+        let root = [7u8; 32];
 
-        let indices = generate_sample_indices(domain_size, num_samples).unwrap();
+        let (indices, proof_of_work) = generate_sample_indices(&root, domain_size, num_samples, 0, 4);
 
         // Check that we have the right number of indices
         assert_eq!(indices.len(), num_samples);
@@ -272,5 +423,53 @@ mod tests {
         for &index in &indices {
             assert!(index < domain_size);
         }
+
+        // A verifier re-deriving from the same inputs gets the same indices
+        let expected =
+            verify_sample_indices(&root, domain_size, num_samples, 0, proof_of_work, 4).unwrap();
+        assert_eq!(indices, expected);
+    }
+
+    #[test]
+    fn test_generate_sample_indices_depends_on_root_and_nonce() {
+        let domain_size = 256;
+        let num_samples = 40;
+
+        let (indices_a, _) = generate_sample_indices(&[1u8; 32], domain_size, num_samples, 0, 0);
+        let (indices_b, _) = generate_sample_indices(&[2u8; 32], domain_size, num_samples, 0, 0);
+        assert_ne!(indices_a, indices_b);
+
+        let (indices_c, _) = generate_sample_indices(&[1u8; 32], domain_size, num_samples, 1, 0);
+        assert_ne!(indices_a, indices_c);
+    }
+
+    #[test]
+    fn test_sample_and_verify_sampling() {
+        let data = b"Hello, FRIEDA! This is some sample data availability payload.";
+        let (commitment, context) = crate::da::commit_with_context(data).unwrap();
+
+        let result = sample(&commitment, &context, 42, 0).unwrap();
+        assert!(result.success);
+        assert!(verify_sampling(&commitment, &result, 0).unwrap());
+    }
+
+    #[test]
+    fn test_verify_sampling_rejects_tampered_value() {
+        let data = b"Hello, FRIEDA! This is some sample data availability payload.";
+        let (commitment, context) = crate::da::commit_with_context(data).unwrap();
+
+        let mut result = sample(&commitment, &context, 42, 0).unwrap();
+        result.values[0] += M31::from_u32_unchecked(1);
+        assert!(!verify_sampling(&commitment, &result, 0).unwrap());
+    }
+
+    #[test]
+    fn test_verify_sampling_rejects_wrong_nonce() {
+        let data = b"Hello, FRIEDA! This is some sample data availability payload.";
+        let (commitment, context) = crate::da::commit_with_context(data).unwrap();
+
+        let mut result = sample(&commitment, &context, 42, 0).unwrap();
+        result.nonce = 43;
+        assert!(!verify_sampling(&commitment, &result, 0).unwrap());
     }
 }
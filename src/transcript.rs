@@ -0,0 +1,115 @@
+//! Fiat–Shamir transcript module
+//!
+//! This module implements a simple hash-chain transcript (a SHA-256 duplex)
+//! that binds the FRI prover and verifier to the same public data, so query
+//! indices and folding challenges are pseudo-random yet deterministically
+//! re-derivable by both sides, rather than being fixed constants.
+
+use sha2::{Digest, Sha256};
+
+use crate::M31;
+
+/// A Fiat–Shamir transcript built from a SHA-256 hash chain.
+///
+/// Public data the prover and verifier agree on (Merkle roots, commitment
+/// metadata, ...) is absorbed in the same order on both sides, and
+/// challenges are squeezed out of the resulting running state.
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    state: [u8; 32],
+    counter: u64,
+}
+
+impl Transcript {
+    /// Creates a new transcript seeded with a domain separator, so distinct
+    /// protocols or DA instances never derive colliding challenges.
+    pub fn new(domain_separator: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(domain_separator);
+        Self {
+            state: hasher.finalize().into(),
+            counter: 0,
+        }
+    }
+
+    /// Absorbs public data into the transcript, updating its running state.
+    pub fn absorb(&mut self, data: &[u8]) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state);
+        hasher.update(data);
+        self.state = hasher.finalize().into();
+        self.counter = 0;
+    }
+
+    /// Squeezes a single pseudo-random 32-byte digest out of the transcript.
+    fn squeeze_digest(&mut self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state);
+        hasher.update(self.counter.to_le_bytes());
+        self.counter += 1;
+        hasher.finalize().into()
+    }
+
+    /// Squeezes `count` pseudo-random indices in `[0, modulus)`.
+    pub fn squeeze_indices(&mut self, count: usize, modulus: usize) -> Vec<usize> {
+        (0..count)
+            .map(|_| {
+                let digest = self.squeeze_digest();
+                u64::from_le_bytes(digest[0..8].try_into().unwrap()) as usize % modulus
+            })
+            .collect()
+    }
+
+    /// Squeezes a pseudo-random folding challenge in the `M31` field.
+    pub fn squeeze_challenge(&mut self) -> M31 {
+        let digest = self.squeeze_digest();
+        let value = u32::from_le_bytes(digest[0..4].try_into().unwrap()) & 0x7FFF_FFFF;
+        M31::from_u32_unchecked(value)
+    }
+
+    /// Computes the digest of the current transcript state concatenated with
+    /// a candidate `nonce`, without mutating the transcript.
+    ///
+    /// This lets a proof-of-work search try many nonces against the same
+    /// absorbed state, and lets a verifier recompute that same digest for
+    /// the one nonce a prover settled on.
+    pub fn digest_with_nonce(&self, nonce: u64) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state);
+        hasher.update(nonce.to_le_bytes());
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indices_are_deterministic() {
+        let mut t1 = Transcript::new(b"FRIEDA_FRI");
+        t1.absorb(&[1, 2, 3]);
+        let mut t2 = Transcript::new(b"FRIEDA_FRI");
+        t2.absorb(&[1, 2, 3]);
+
+        assert_eq!(t1.squeeze_indices(10, 256), t2.squeeze_indices(10, 256));
+    }
+
+    #[test]
+    fn test_indices_depend_on_absorbed_data() {
+        let mut t1 = Transcript::new(b"FRIEDA_FRI");
+        t1.absorb(&[1, 2, 3]);
+        let mut t2 = Transcript::new(b"FRIEDA_FRI");
+        t2.absorb(&[4, 5, 6]);
+
+        assert_ne!(t1.squeeze_indices(10, 256), t2.squeeze_indices(10, 256));
+    }
+
+    #[test]
+    fn test_domain_separator_changes_output() {
+        let mut t1 = Transcript::new(b"FRIEDA_FRI");
+        let mut t2 = Transcript::new(b"FRIEDA_SAMPLING");
+
+        assert_ne!(t1.squeeze_indices(10, 256), t2.squeeze_indices(10, 256));
+    }
+}
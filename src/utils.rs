@@ -1,13 +1,20 @@
 use bitvec::{bitarr, field::BitField, order::Lsb0, vec::BitVec};
+use sha2::{Digest, Sha256};
 use stwo_prover::core::{
     backend::CpuBackend,
+    circle::Coset,
     fields::{
         cm31::CM31,
         m31::{BaseField, M31},
     },
-    poly::circle::{CirclePoly, SecureCirclePoly},
+    poly::{
+        circle::{CircleDomain, CircleEvaluation, CirclePoly, PolyOps, SecureCirclePoly},
+        BitReversedOrder,
+    },
 };
 
+use crate::{FriedaError, Result};
+
 /// Convert a byte slice to a vector of BaseField elements, where each element is the
 /// a felt containing the bytes. A felt can be up to 2**31 - 1
 pub fn bytes_to_felt_le(data: &[u8]) -> Vec<BaseField> {
@@ -22,9 +29,31 @@ pub fn bytes_to_felt_le(data: &[u8]) -> Vec<BaseField> {
 }
 
 pub fn polynomial_from_bytes(data: &[u8]) -> SecureCirclePoly<CpuBackend> {
-    let coefficients = bytes_to_felt_le(data);
+    let coefficients = encode_framed(data);
     polynomial_from_felts(coefficients)
 }
+
+/// Encodes `data` the same way [`bytes_to_felt_le`] does, but prepends a
+/// single header felt carrying the exact byte length, so [`decode_framed`]
+/// can undo the 30-bit chunking's zero-padding and recover `data` exactly
+pub fn encode_framed(data: &[u8]) -> Vec<BaseField> {
+    let mut felts = Vec::with_capacity(1 + data.len() / 3);
+    felts.push(BaseField::from_u32_unchecked(data.len() as u32));
+    felts.extend(bytes_to_felt_le(data));
+    felts
+}
+
+/// Decodes felts produced by [`encode_framed`], truncating
+/// [`felts_to_bytes_le`]'s 30-bit-boundary padding down to the exact
+/// original byte count recorded in the header felt
+pub fn decode_framed(felts: &[BaseField]) -> Vec<u8> {
+    let Some((header, payload)) = felts.split_first() else {
+        return Vec::new();
+    };
+    let mut bytes = felts_to_bytes_le(payload);
+    bytes.truncate(header.0 as usize);
+    bytes
+}
 pub fn polynomial_from_felts(mut coefficients: Vec<M31>) -> SecureCirclePoly<CpuBackend> {
     let next_power_of_2 = 1 << ((coefficients.len() as f64).log2().ceil() as u32).max(2);
     coefficients.resize(next_power_of_2, BaseField::from(0));
@@ -39,6 +68,151 @@ pub fn polynomial_from_felts(mut coefficients: Vec<M31>) -> SecureCirclePoly<Cpu
     let col4 = CirclePoly::<CpuBackend>::new(col4);
     SecureCirclePoly([col1, col2, col3, col4])
 }
+/// Evaluates `poly`'s four secure-field columns over the blown-up coset
+/// domain, via the same single `evaluate_with_twiddles` call (and shared
+/// twiddle precomputation) `commit::commit` and `commit::batch_commit` used
+/// to perform inline; factored out here purely so both call sites share one
+/// definition, with no change to what gets computed or how.
+pub fn coset_lde_batch(
+    poly: &SecureCirclePoly<CpuBackend>,
+    log_blowup_factor: u32,
+) -> [CircleEvaluation<CpuBackend, BaseField, BitReversedOrder>; 4] {
+    let coset = Coset::half_odds(poly.log_size() + log_blowup_factor - 1);
+    let domain = CircleDomain::new(coset);
+    let twiddles = CpuBackend::precompute_twiddles(coset);
+    poly.evaluate_with_twiddles(domain, &twiddles).columns
+}
+
+/// A binary Merkle tree over SHA-256, used by the hand-rolled FRI machinery
+/// in [`crate::fri`], [`crate::fri_ldt`], [`crate::da`], [`crate::sampling`]
+/// and [`crate::vid`] to commit to `M31` evaluation vectors.
+///
+/// Leaves are padded up to the next power of two (by repeating the last
+/// leaf) so every authentication path has the same, domain-size-derived
+/// depth regardless of how many leaves were actually hashed in.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// Every layer of the tree, from the leaves (`layers[0]`) up to the root
+    /// (`layers.last()`, a single element)
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// The root of the tree
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// The authentication path proving the leaf at `index` is part of this
+    /// tree: one sibling hash per layer, from the leaves up to (but not
+    /// including) the root
+    pub fn get_auth_path(&self, index: usize) -> Result<Vec<[u8; 32]>> {
+        let num_leaves = self.layers[0].len();
+        if index >= num_leaves {
+            return Err(FriedaError::InvalidInput(format!(
+                "leaf index {index} out of bounds for a tree with {num_leaves} leaves"
+            )));
+        }
+
+        let mut path = Vec::with_capacity(self.layers.len() - 1);
+        let mut idx = index;
+        for layer in &self.layers[..self.layers.len() - 1] {
+            path.push(layer[idx ^ 1]);
+            idx /= 2;
+        }
+        Ok(path)
+    }
+
+    /// Verifies that `leaf_hash` at `index` authenticates against `root`
+    /// under `auth_path`, by folding the path back up to a root and
+    /// comparing it to `root`
+    pub fn verify_inclusion(
+        leaf_hash: &[u8; 32],
+        index: usize,
+        auth_path: &[[u8; 32]],
+        root: &[u8; 32],
+    ) -> bool {
+        let mut hash = *leaf_hash;
+        let mut idx = index;
+        for sibling in auth_path {
+            hash = if idx % 2 == 0 {
+                hash_pair(&hash, sibling)
+            } else {
+                hash_pair(sibling, &hash)
+            };
+            idx /= 2;
+        }
+        &hash == root
+    }
+}
+
+/// Hashes arbitrary bytes with SHA-256
+pub fn hash(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
+
+/// Hashes a pair of sibling nodes into their parent
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice(left);
+    bytes.extend_from_slice(right);
+    hash(&bytes)
+}
+
+/// Encodes a single `M31` element as its little-endian byte representation,
+/// the leaf encoding [`create_merkle_tree`] hashes
+pub fn m31_to_bytes(value: M31) -> [u8; 4] {
+    value.0.to_le_bytes()
+}
+
+/// Hashes a row of `M31` values (every batched column's value at one shared
+/// domain index) into a single leaf, the leaf encoding
+/// [`create_merkle_tree_rows`] hashes
+pub fn hash_row(values: &[M31]) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(values.len() * 4);
+    for value in values {
+        bytes.extend_from_slice(&m31_to_bytes(*value));
+    }
+    hash(&bytes)
+}
+
+/// Builds a [`MerkleTree`] committing to a single evaluation vector, one
+/// leaf per element
+pub fn create_merkle_tree(evaluations: &[M31]) -> MerkleTree {
+    build_merkle_tree(evaluations.iter().map(|&value| hash(&m31_to_bytes(value))).collect())
+}
+
+/// Builds a [`MerkleTree`] committing to several columns sharing a domain,
+/// one leaf per domain index hashing every column's value at that index
+/// together (see [`crate::fri::Column`])
+pub fn create_merkle_tree_rows(rows: &[Vec<M31>]) -> MerkleTree {
+    build_merkle_tree(rows.iter().map(|row| hash_row(row)).collect())
+}
+
+/// Builds every layer of a [`MerkleTree`] from its (possibly not
+/// power-of-two) leaves, padding by repeating the last leaf
+fn build_merkle_tree(mut leaves: Vec<[u8; 32]>) -> MerkleTree {
+    if leaves.is_empty() {
+        leaves.push(hash(&[]));
+    }
+    let last = *leaves.last().unwrap();
+    leaves.resize(leaves.len().next_power_of_two(), last);
+
+    let mut layers = vec![leaves];
+    while layers.last().unwrap().len() > 1 {
+        let parent = layers
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+        layers.push(parent);
+    }
+    MerkleTree { layers }
+}
+
 pub fn felts_to_bytes_le(felts: &[BaseField]) -> Vec<u8> {
     let mut bitvec = BitVec::<u8, Lsb0>::new();
     for felt in felts {
@@ -80,4 +254,57 @@ mod tests {
             assert_eq!(felt[2], BaseField::from(0));
         }
     }
+
+    #[test]
+    fn test_encode_decode_framed_round_trip() {
+        for data in [
+            b"".as_slice(),
+            b"a".as_slice(),
+            b"hello, frieda".as_slice(),
+            &[0u8; 61],
+            &[7u8; 123],
+        ] {
+            let felts = encode_framed(data);
+            assert_eq!(decode_framed(&felts), data);
+        }
+    }
+
+    #[test]
+    fn test_merkle_tree_round_trips_inclusion() {
+        let evaluations: Vec<M31> = (0..5).map(M31::from).collect();
+        let tree = create_merkle_tree(&evaluations);
+        let root = tree.root();
+
+        for (index, &value) in evaluations.iter().enumerate() {
+            let leaf_hash = hash(&m31_to_bytes(value));
+            let auth_path = tree.get_auth_path(index).unwrap();
+            assert!(MerkleTree::verify_inclusion(&leaf_hash, index, &auth_path, &root));
+        }
+    }
+
+    #[test]
+    fn test_merkle_tree_rejects_tampered_value_or_wrong_root() {
+        let evaluations: Vec<M31> = (0..5).map(M31::from).collect();
+        let tree = create_merkle_tree(&evaluations);
+        let root = tree.root();
+        let auth_path = tree.get_auth_path(2).unwrap();
+
+        let tampered_hash = hash(&m31_to_bytes(M31::from(999)));
+        assert!(!MerkleTree::verify_inclusion(&tampered_hash, 2, &auth_path, &root));
+
+        let other_root = create_merkle_tree(&[M31::from(123)]).root();
+        let leaf_hash = hash(&m31_to_bytes(evaluations[2]));
+        assert!(!MerkleTree::verify_inclusion(&leaf_hash, 2, &auth_path, &other_root));
+    }
+
+    #[test]
+    fn test_decode_framed_truncates_chunking_padding() {
+        // `bytes_to_felt_le` pads the last felt to a 30-bit boundary, which
+        // on its own can't be undone without the length header.
+        let data = [1u8, 2, 3];
+        let felts = encode_framed(&data);
+        let unframed = felts_to_bytes_le(&felts[1..]);
+        assert!(unframed.len() > data.len());
+        assert_eq!(decode_framed(&felts), data);
+    }
 }
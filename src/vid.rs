@@ -0,0 +1,296 @@
+//! Verifiable Information Dispersal (VID) module
+//!
+//! This module builds a verifiable information dispersal scheme on top of
+//! the FRI commitment in [`crate::fri`]: a blob is erasure-coded into `n`
+//! shares such that any `k` of them are enough to reconstruct it, and each
+//! share is independently verifiable against a single commitment root. This
+//! lets a data provider hand one share to each of `n` storage nodes while a
+//! light client only needs to fetch `k` of them. A FRI low-degree proof
+//! generated alongside the shares guards against inconsistent encoding: it
+//! binds the commitment to an actual degree-`< k` codeword, so distinct
+//! quorums of `k` shares can't interpolate to different messages.
+
+use std::collections::HashMap;
+
+use stwo_prover::core::fields::FieldExpOps;
+
+use crate::{
+    da::{bytes_to_field_elements, field_elements_to_bytes},
+    field::get_primitive_root_of_unity,
+    fri::{FriProver, FriVerifier},
+    polynomial,
+    utils::{self, MerkleTree},
+    Commitment, CommitmentMetadata, FriProof, FriedaError, Result, M31,
+};
+
+const FIELD_SIZE: usize = 31; // M31 field
+const BATCH_SIZE: usize = 1; // VID disperses a single blob at a time
+const NUM_QUERIES: usize = 40; // sampled by the low-degree proof, not by individual shares
+const FAN_IN: usize = 4;
+const POW_BITS: u32 = 0;
+
+/// Picks how far the low-degree proof must fold a degree-`< message_len`
+/// codeword down before checking that its degree is below that base
+/// dimension
+///
+/// A fixed base dimension at or above `message_len` would let
+/// [`crate::fri::FriProver::num_rounds`] run zero folding rounds, in which
+/// case the final layer is just the untouched codeword and the low-degree
+/// check holds vacuously for *any* committed vector -- exactly the
+/// inconsistent-encoding hole this proof exists to close. Scaling it down
+/// with `message_len` keeps at least one real folding round for every
+/// `k >= 2`; with `k = 1` there is no folding round, and the final-layer
+/// check on the whole codeword asks for a constant.
+fn base_dimension_for(message_len: usize) -> usize {
+    (message_len / FAN_IN).max(1)
+}
+
+/// A single dispersed share of a blob
+///
+/// Carries the share's chunk of the Reed-Solomon codeword (one evaluation
+/// of the encoded polynomial) along with the Merkle authentication path
+/// proving that evaluation is part of the codeword committed to in
+/// `Commitment::root`.
+#[derive(Clone, Debug)]
+pub struct Share {
+    /// The share's position in the evaluation domain
+    pub index: usize,
+    /// The codeword value at `index`
+    pub value: M31,
+    /// The Merkle authentication path proving `value` is the leaf at `index`
+    pub auth_path: Vec<[u8; 32]>,
+}
+
+/// Disperses a blob into `n` shares such that any `k` of them reconstruct it
+///
+/// Alongside the shares, this also generates a FRI low-degree proof over the
+/// encoded codeword, bound to the commitment root. A Merkle root alone only
+/// binds a committer to *some* vector of `n` values; without this proof a
+/// malicious disperser could commit to values that aren't actually a
+/// degree-`< k` Reed-Solomon codeword, in which case different quorums of
+/// `k` shares interpolate to different "original" messages (each
+/// individual [`Share`] would still pass [`verify_share`], since that only
+/// checks Merkle inclusion). [`recover`] checks this proof before trusting
+/// its interpolation.
+///
+/// # Arguments
+///
+/// * `data` - The raw data bytes to disperse
+/// * `n` - The number of shares to produce (must be a power of 2)
+/// * `k` - The number of shares required to reconstruct the data (must
+///   divide `n` and be a power of 2)
+///
+/// # Returns
+///
+/// The commitment to the dispersed blob, a low-degree proof binding that
+/// commitment to a genuine degree-`< k` codeword, and one `Share` per
+/// storage node
+pub fn disperse(data: &[u8], n: usize, k: usize) -> Result<(Commitment, FriProof, Vec<Share>)> {
+    if !n.is_power_of_two() || !k.is_power_of_two() || n % k != 0 {
+        return Err(FriedaError::InvalidInput(format!(
+            "n={n} and k={k} must be powers of 2 with k dividing n"
+        )));
+    }
+
+    let expansion_factor = n / k;
+
+    let mut message = bytes_to_field_elements(data);
+    if message.len() > k {
+        return Err(FriedaError::InvalidInput(format!(
+            "data encodes to {} field elements, which exceeds k={k}",
+            message.len()
+        )));
+    }
+    message.resize(k, M31::default());
+
+    let encoded = polynomial::reed_solomon_encode(&message, expansion_factor)?;
+    debug_assert_eq!(encoded.len(), n);
+
+    let prover = FriProver::new(
+        n,
+        expansion_factor,
+        BATCH_SIZE,
+        FIELD_SIZE,
+        NUM_QUERIES,
+        FAN_IN,
+        base_dimension_for(k),
+        POW_BITS,
+    );
+    let (root, tree) = prover.commit(&encoded)?;
+    let low_degree_proof = prover.generate_proof(&encoded, &tree)?;
+
+    let commitment = Commitment {
+        root,
+        metadata: CommitmentMetadata {
+            domain_size: n,
+            expansion_factor,
+            batch_size: BATCH_SIZE,
+            field_size: FIELD_SIZE,
+            bit_length: data.len() * 8,
+        },
+    };
+
+    let shares = (0..n)
+        .map(|index| {
+            Ok(Share {
+                index,
+                value: encoded[index],
+                auth_path: tree.get_auth_path(index)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((commitment, low_degree_proof, shares))
+}
+
+/// Verifies that a share is authentic against a commitment
+///
+/// # Arguments
+///
+/// * `commitment` - The commitment produced by `disperse`
+/// * `share` - The share to verify
+///
+/// # Returns
+///
+/// `true` if the share's value and authentication path are consistent with
+/// `commitment.root`
+pub fn verify_share(commitment: &Commitment, share: &Share) -> bool {
+    let leaf_hash = utils::hash(&utils::m31_to_bytes(share.value));
+    MerkleTree::verify_inclusion(&leaf_hash, share.index, &share.auth_path, &commitment.root)
+}
+
+/// Recovers the original blob from at least `k` verified shares
+///
+/// Checks `low_degree_proof` against `commitment` before trusting any
+/// share's interpolation: without it, a malicious disperser's shares could
+/// still all pass [`verify_share`] while interpolating to different
+/// messages depending on which quorum of `k` is gathered (see [`disperse`]).
+///
+/// # Arguments
+///
+/// * `commitment` - The commitment the shares were dispersed under
+/// * `low_degree_proof` - The proof [`disperse`] generated binding
+///   `commitment` to a genuine degree-`< k` codeword
+/// * `shares` - The shares gathered from storage nodes; any shares that
+///   fail verification are discarded rather than causing an error, so
+///   callers don't need to pre-filter
+///
+/// # Returns
+///
+/// The reconstructed original data, or an error if the low-degree proof
+/// doesn't check out or fewer than `k` shares verify
+pub fn recover(
+    commitment: &Commitment,
+    low_degree_proof: &FriProof,
+    shares: &[Share],
+) -> Result<Vec<u8>> {
+    let message_len = commitment.metadata.domain_size / commitment.metadata.expansion_factor;
+
+    let verifier = FriVerifier::new(
+        commitment.metadata.domain_size,
+        commitment.metadata.expansion_factor,
+        commitment.metadata.batch_size,
+        commitment.metadata.field_size,
+        FAN_IN,
+        base_dimension_for(message_len),
+        POW_BITS,
+    );
+    if !verifier.verify(&commitment.root, low_degree_proof)? {
+        return Err(FriedaError::InvalidInput(
+            "low-degree proof does not verify against the commitment".to_string(),
+        ));
+    }
+
+    let omega = get_primitive_root_of_unity(commitment.metadata.domain_size);
+    let mut verified: HashMap<usize, (M31, M31)> = HashMap::new();
+    for share in shares {
+        if !verify_share(commitment, share) {
+            continue;
+        }
+        let point = omega.pow(share.index as u128);
+        verified.insert(share.index, (point, share.value));
+    }
+
+    if verified.len() < message_len {
+        return Err(FriedaError::InvalidInput(format!(
+            "not enough verified shares to recover: got {}, need {}",
+            verified.len(),
+            message_len
+        )));
+    }
+
+    let (points, values): (Vec<M31>, Vec<M31>) =
+        verified.values().take(message_len).cloned().unzip();
+    let coeffs = polynomial::lagrange_interpolation(&values, &points)?;
+    let message = coeffs[..message_len].to_vec();
+
+    Ok(field_elements_to_bytes(&message, commitment.metadata.bit_length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disperse_verify_and_recover() {
+        // 13 bytes pack into 4 field elements, so the blob fits k = 4.
+        let data = b"VID over FRI.";
+
+        let (commitment, low_degree_proof, shares) = disperse(data, 16, 4).unwrap();
+        assert_eq!(shares.len(), 16);
+
+        for share in &shares {
+            assert!(verify_share(&commitment, share));
+        }
+
+        // Any k = 4 of the 16 shares should be enough to recover the blob.
+        let quorum = &shares[..4];
+        let recovered = recover(&commitment, &low_degree_proof, quorum).unwrap();
+        assert_eq!(&recovered[..data.len()], &data[..]);
+    }
+
+    #[test]
+    fn test_recover_rejects_insufficient_shares() {
+        let data = b"short";
+
+        let (commitment, low_degree_proof, shares) = disperse(data, 16, 4).unwrap();
+
+        assert!(recover(&commitment, &low_degree_proof, &shares[..3]).is_err());
+    }
+
+    #[test]
+    fn test_recover_rejects_inconsistent_encoding() {
+        // An attacker who commits to an arbitrary (non-codeword) vector
+        // instead of a genuine degree-<k Reed-Solomon encoding can't forge
+        // a matching low-degree proof, so `recover` must reject it even
+        // though every individual share still passes `verify_share`.
+        let data = b"VID over FRI.";
+        let (commitment, _honest_proof, mut shares) = disperse(data, 16, 4).unwrap();
+
+        let prover = FriProver::new(
+            commitment.metadata.domain_size,
+            commitment.metadata.expansion_factor,
+            commitment.metadata.batch_size,
+            commitment.metadata.field_size,
+            NUM_QUERIES,
+            FAN_IN,
+            base_dimension_for(commitment.metadata.domain_size / commitment.metadata.expansion_factor),
+            POW_BITS,
+        );
+        let garbage: Vec<M31> = (0..commitment.metadata.domain_size as u32).map(M31::from).collect();
+        let (garbage_root, garbage_tree) = prover.commit(&garbage).unwrap();
+        let forged_proof = prover.generate_proof(&garbage, &garbage_tree).unwrap();
+
+        let mut forged_commitment = commitment;
+        forged_commitment.root = garbage_root;
+        for (share, &value) in shares.iter_mut().zip(&garbage) {
+            share.value = value;
+            share.auth_path = garbage_tree.get_auth_path(share.index).unwrap();
+        }
+        for share in &shares {
+            assert!(verify_share(&forged_commitment, share));
+        }
+
+        assert!(recover(&forged_commitment, &forged_proof, &shares[..4]).is_err());
+    }
+}